@@ -0,0 +1,270 @@
+use crate::{
+	meter::{Benches, Schedule},
+	Addresses, Eip, Format, Selection,
+};
+use eip_test_gen_core::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+	error::Error,
+	fs,
+	path::{Path, PathBuf},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+pub fn generate<S: Suite>(selection: &Selection, out: &Path) -> Result<()> {
+	fs::create_dir_all(out)?;
+	let seed = selection.seed.unwrap_or(DEFAULT_SEED);
+	// Keep the configurations recorded by earlier runs with the same seed.
+	let mut metadata = match recorded_metadata::<S>(out)? {
+		Some(metadata) if metadata.seed == seed => metadata,
+		_ => Metadata::new(seed),
+	};
+	for op in selection.ops(S::OPERATIONS)? {
+		if selection.set.success() {
+			let config = selection.config(Config::default_for(op));
+			let vectors = op.gen_vectors::<S>(seed, &config);
+			let path = write_fixture::<S>(out, op, &vectors)?;
+			println!("wrote {} vectors to {}", vectors.len(), path.display());
+			metadata.configs.insert(op.name().to_string(), config);
+		}
+		if selection.set.fail() {
+			let vectors = op.gen_fail_vectors::<S>(seed);
+			let path = write_fail_fixture::<S>(out, op, &vectors)?;
+			println!("wrote {} vectors to {}", vectors.len(), path.display());
+		}
+	}
+	write_metadata(&metadata, &out.join(metadata_file_name::<S>()))?;
+	Ok(())
+}
+
+pub fn list<S: Suite>(eip: Eip) {
+	println!("EIP-{}", eip.name());
+	for &op in S::OPERATIONS {
+		println!("  {:<12}{:<28}{}", op.name(), file_name::<S>(op), fail_file_name::<S>(op));
+	}
+}
+
+pub fn verify<S: Suite>(selection: &Selection, dir: &Path) -> Result<()> {
+	let metadata = recorded_metadata::<S>(dir)?.unwrap_or_else(|| Metadata::new(DEFAULT_SEED));
+	let seed = selection.seed.unwrap_or(metadata.seed);
+	let (mut missing, mut mismatches, mut rejected) = (0, 0, 0);
+	for op in selection.ops(S::OPERATIONS)? {
+		if selection.set.success() {
+			let config = selection.config(metadata.config(op));
+			let path = dir.join(file_name::<S>(op));
+			if present(&path, &mut missing) {
+				mismatches += usize::from(!check(&path, || op.gen_vectors::<S>(seed, &config))?);
+				rejected += usize::from(!execute(&path, |vector: &VectorSuccess| {
+					let input = hex::decode(&vector.input)?;
+					let gas = op.gas::<S>(input.len());
+					let mismatch = match op.execute::<S>(&input) {
+						Ok(_) if gas != vector.gas => format!("charges {gas} gas"),
+						Ok(output) if hex::encode(&output) == vector.expected => return Ok(None),
+						Ok(output) => format!("outputs {}", hex::encode(output)),
+						Err(err) => format!("fails with `{}`", S::error_message(err)),
+					};
+					Ok(Some(format!("{} {mismatch}", vector.name)))
+				})?);
+			}
+		}
+		if selection.set.fail() {
+			let path = dir.join(fail_file_name::<S>(op));
+			if present(&path, &mut missing) {
+				mismatches += usize::from(!check(&path, || op.gen_fail_vectors::<S>(seed))?);
+				rejected += usize::from(!execute(&path, |vector: &VectorFail| {
+					let mismatch = match op.execute::<S>(&hex::decode(&vector.input)?) {
+						Ok(output) => format!("outputs {}", hex::encode(output)),
+						Err(err) => match S::error_message(err) {
+							error if error == vector.expected_error => return Ok(None),
+							error => format!("fails with `{error}`"),
+						},
+					};
+					Ok(Some(format!("{} {mismatch}", vector.name)))
+				})?);
+			}
+		}
+	}
+	let unknown = unknown_files::<S>(dir)?;
+	for path in &unknown {
+		println!("FAIL {} (unknown)", path.display());
+	}
+	let mut errors = vec![];
+	if missing > 0 {
+		errors.push(format!("{missing} file(s) missing"));
+	}
+	if mismatches > 0 {
+		errors.push(format!("{mismatches} file(s) differ from a fresh generation"));
+	}
+	if rejected > 0 {
+		errors.push(format!("{rejected} file(s) disagree with the reference precompiles"));
+	}
+	if !unknown.is_empty() {
+		errors.push(format!("{} file(s) belong to no operation", unknown.len()));
+	}
+	if !errors.is_empty() {
+		return Err(errors.join(", ").into());
+	}
+	Ok(())
+}
+
+pub fn state_tests<S: Suite>(
+	selection: &Selection,
+	addresses: &Addresses,
+	dir: &Path,
+	out: &Path,
+	network: &str,
+) -> Result<()> {
+	export::<S>(selection, addresses, dir, out, |address, test, calls| {
+		let filler = state_test_filler(address, test, network, calls);
+		let path = out.join(format!("{test}Filler.json"));
+		fs::write(&path, serde_json::to_string_pretty(&filler)? + "\n")?;
+		println!("wrote {} state tests to {}", calls.len(), path.display());
+		Ok(())
+	})
+}
+
+pub fn foundry_tests<S: Suite>(
+	selection: &Selection,
+	addresses: &Addresses,
+	dir: &Path,
+	out: &Path,
+) -> Result<()> {
+	export::<S>(selection, addresses, dir, out, |address, test, calls| {
+		let path = out.join(format!("{}.t.sol", foundry_contract_name(test)));
+		fs::write(&path, foundry_test(address, test, calls))?;
+		println!("wrote {} foundry tests to {}", calls.len(), path.display());
+		Ok(())
+	})
+}
+
+pub fn gas_schedule(
+	benches: &[PathBuf],
+	curve: &str,
+	mul_gas: Option<u64>,
+	pair_gas: Option<u64>,
+	format: Format,
+) -> Result<()> {
+	let schedule = Schedule::derive(&Benches::read(benches)?, curve, mul_gas, pair_gas)?;
+	match format {
+		Format::Json => println!("{}", schedule.to_json()),
+		Format::Markdown => print!("{}", schedule.to_markdown()),
+	}
+	Ok(())
+}
+
+/// Metadata recorded by `generate` in `dir`, if any.
+fn recorded_metadata<S: Suite>(dir: &Path) -> Result<Option<Metadata>> {
+	let path = dir.join(metadata_file_name::<S>());
+	if !path.exists() {
+		return Ok(None);
+	}
+	Ok(Some(read_metadata(&path)?))
+}
+
+/// Whether the vector file at `path` exists, counting it in `missing` if not.
+fn present(path: &Path, missing: &mut usize) -> bool {
+	if path.exists() {
+		return true;
+	}
+	println!("FAIL {} (missing)", path.display());
+	*missing += 1;
+	false
+}
+
+/// `*.json` files in `dir` that are neither vector files of `S` nor its metadata, e.g. left over
+/// from a renamed operation.
+pub(crate) fn unknown_files<S: Suite>(dir: &Path) -> Result<Vec<PathBuf>> {
+	let known: Vec<String> = S::OPERATIONS
+		.iter()
+		.flat_map(|&op| [file_name::<S>(op), fail_file_name::<S>(op)])
+		.chain([metadata_file_name::<S>()])
+		.collect();
+	let mut unknown = vec![];
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+		if name.ends_with(".json") && !known.iter().any(|known| known == name) {
+			unknown.push(path);
+		}
+	}
+	unknown.sort();
+	Ok(unknown)
+}
+
+/// Compares the file at `path` with the vectors returned by `vectors`.
+fn check<T: Serialize>(path: &Path, vectors: impl FnOnce() -> Vec<T>) -> Result<bool> {
+	let ok = fs::read_to_string(path)? == serialize_vectors(&vectors());
+	println!("{} {}", if ok { "ok  " } else { "FAIL" }, path.display());
+	Ok(ok)
+}
+
+/// Passes the precompile address and calls of each selected vector file in `dir` to `write` with
+/// the file name without extension, e.g. `bn256Add`. Missing files and operations without an
+/// address are skipped.
+fn export<S: Suite>(
+	selection: &Selection,
+	addresses: &Addresses,
+	dir: &Path,
+	out: &Path,
+	mut write: impl FnMut(u64, &str, &[PrecompileCall]) -> Result<()>,
+) -> Result<()> {
+	if let Some((op, _)) = addresses.overrides.iter().find(|(op, _)| !S::OPERATIONS.contains(op)) {
+		return Err(format!("EIP-{} has no {op} precompile", selection.eip.name()).into());
+	}
+	fs::create_dir_all(out)?;
+	for op in selection.ops(S::OPERATIONS)? {
+		let Some(address) = addresses.get::<S>(op) else {
+			println!("skip {op} (no precompile address)");
+			continue;
+		};
+		if selection.set.success() {
+			let name = file_name::<S>(op);
+			if let Some(calls) = read_calls(&dir.join(&name), PrecompileCall::success)? {
+				write(address, name.trim_end_matches(".json"), &calls)?;
+			}
+		}
+		if selection.set.fail() {
+			let name = fail_file_name::<S>(op);
+			let call = |vector: &VectorFail| PrecompileCall::fail::<S>(op, vector);
+			if let Some(calls) = read_calls(&dir.join(&name), call)? {
+				write(address, name.trim_end_matches(".json"), &calls)?;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Precompile calls of the vectors in `path`, `None` if the file is missing. Fails if two
+/// vectors share a name.
+fn read_calls<T: DeserializeOwned>(
+	path: &Path,
+	call: impl Fn(&T) -> PrecompileCall,
+) -> Result<Option<Vec<PrecompileCall>>> {
+	if !path.exists() {
+		println!("skip {} (missing)", path.display());
+		return Ok(None);
+	}
+	let calls: Vec<_> = read_vectors::<T>(path)?.iter().map(call).collect();
+	check_unique_names(&calls).map_err(|err| format!("{}: {err}", path.display()))?;
+	Ok(Some(calls))
+}
+
+/// Runs the reference precompiles over the vectors in `path`. `run` describes how the precompile
+/// disagrees with a vector by name, `None` if it agrees.
+fn execute<T: DeserializeOwned>(
+	path: &Path,
+	run: impl Fn(&T) -> Result<Option<String>>,
+) -> Result<bool> {
+	let mut ok = true;
+	for vector in read_vectors::<T>(path)? {
+		if let Some(mismatch) = run(&vector)? {
+			println!("FAIL {}: {mismatch}", path.display());
+			ok = false;
+		}
+	}
+	if ok {
+		println!("ok   {} (executed)", path.display());
+	}
+	Ok(ok)
+}
@@ -0,0 +1,263 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use eip_196_test_gen::Eip196;
+use eip_2537_test_gen::Eip2537;
+use eip_2539_test_gen::Eip2539;
+use eip_3026_test_gen::Eip3026;
+use eip_test_gen_core::{Config, Operation, Sizes, Suite};
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+mod cmd;
+mod meter;
+
+#[derive(Parser)]
+#[command(name = "eip-test-gen", version, about = "Generate test vectors for curve precompiles")]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Generate vector files into a directory.
+	Generate {
+		#[command(flatten)]
+		selection: Selection,
+		/// Directory the vector files are written to.
+		#[arg(long, short, default_value = ".")]
+		out: PathBuf,
+	},
+	/// List the vector files of each EIP.
+	List {
+		/// Only list this EIP.
+		#[arg(long, short)]
+		eip: Option<Eip>,
+	},
+	/// Check that a directory holds exactly the vector files of an EIP and that they match a fresh
+	/// generation and the reference precompiles.
+	Verify {
+		#[command(flatten)]
+		selection: Selection,
+		/// Directory holding the vector files, e.g. `eip-2539/testdata`.
+		#[arg(long, short)]
+		dir: PathBuf,
+	},
+	/// Export the vector files in a directory as ethereum/tests GeneralStateTests fillers.
+	StateTests {
+		#[command(flatten)]
+		selection: Selection,
+		/// Directory holding the vector files, e.g. `eip-2539/testdata`.
+		#[arg(long, short)]
+		dir: PathBuf,
+		/// Directory the fillers are written to.
+		#[arg(long, short, default_value = ".")]
+		out: PathBuf,
+		/// Forks the expectations apply to, e.g. `>=Prague` for the EIP-2537 addresses. EIP-2539
+		/// and EIP-3026 are on no fork, their addresses follow the EIP-2537 ones.
+		#[arg(long)]
+		network: String,
+		#[command(flatten)]
+		addresses: Addresses,
+	},
+	/// Export the vector files in a directory as Foundry tests calling the precompiles.
+	FoundryTests {
+		#[command(flatten)]
+		selection: Selection,
+		/// Directory holding the vector files, e.g. `eip-2539/testdata`.
+		#[arg(long, short)]
+		dir: PathBuf,
+		/// Directory the `.t.sol` files are written to.
+		#[arg(long, short, default_value = ".")]
+		out: PathBuf,
+		#[command(flatten)]
+		addresses: Addresses,
+	},
+	/// Derive the gas schedule of a curve from criterion `--message-format=json` output.
+	GasSchedule {
+		/// Files of criterion messages holding the ECRECOVER and BN pairing calibration benches and
+		/// the benches of the curve, e.g. `eip-3026/gas-meter/benches.json`.
+		#[arg(required = true)]
+		benches: Vec<PathBuf>,
+		/// Curve of the benches as named in their groups, e.g. `BW6_761` or `BLS12_377`. Benches
+		/// of other curves in the files are ignored.
+		#[arg(long)]
+		curve: String,
+		/// Mul price the multiexp discounts are relative to, the measured G1 mul gas if omitted.
+		#[arg(long)]
+		mul_gas: Option<u64>,
+		/// Per pair price the pairing base is relative to, the measured per pair gas if omitted.
+		#[arg(long)]
+		pair_gas: Option<u64>,
+		#[arg(long, short, value_enum, default_value_t = Format::Json)]
+		format: Format,
+	},
+}
+
+#[derive(Args)]
+struct Selection {
+	#[arg(long, short)]
+	eip: Eip,
+	/// Operations to select, all of them if omitted.
+	#[arg(long = "op", value_delimiter = ',', value_parser = Operation::from_str)]
+	ops: Vec<Operation>,
+	#[arg(long, short, value_enum, default_value_t = Set::All)]
+	set: Set,
+	/// Seed of the vector RNG. `verify` falls back to the seed recorded next to the vectors.
+	#[arg(long)]
+	seed: Option<u64>,
+	/// Number of add and mul vectors.
+	#[arg(long)]
+	count: Option<usize>,
+	/// Multiexp and pairing pair counts, e.g. `1..=128`, `1..=128:8` or `1,2,4,8`.
+	#[arg(long, value_parser = Sizes::from_str)]
+	sizes: Option<Sizes>,
+}
+
+impl Selection {
+	/// Selected operations out of the `supported` ones of an EIP.
+	fn ops(&self, supported: &[Operation]) -> Result<Vec<Operation>, String> {
+		if self.ops.is_empty() {
+			return Ok(supported.to_vec());
+		}
+		match self.ops.iter().find(|op| !supported.contains(op)) {
+			Some(op) => Err(format!("EIP-{} has no {op} precompile", self.eip.name())),
+			None => Ok(self.ops.clone()),
+		}
+	}
+
+	/// `base` with the `--count` and `--sizes` overrides applied.
+	fn config(&self, base: Config) -> Config {
+		Config {
+			count: self.count.unwrap_or(base.count),
+			sizes: self.sizes.clone().unwrap_or(base.sizes),
+		}
+	}
+}
+
+#[derive(Args)]
+struct Addresses {
+	/// Precompile addresses overriding the ones of the EIP, e.g. `g1add=0x0b,pairing=0x0f`.
+	#[arg(
+		long = "address",
+		value_name = "OP=ADDRESS",
+		value_delimiter = ',',
+		value_parser = parse_address
+	)]
+	overrides: Vec<(Operation, u64)>,
+}
+
+impl Addresses {
+	/// Address of the precompile of `op`, the last override or else [`Suite::address`].
+	fn get<S: Suite>(&self, op: Operation) -> Option<u64> {
+		let overridden = self.overrides.iter().rev().find(|(o, _)| *o == op);
+		overridden.map(|&(_, address)| address).or_else(|| S::address(op))
+	}
+}
+
+/// Parses `op=address`, the address in hex with a `0x` prefix or in decimal.
+fn parse_address(s: &str) -> Result<(Operation, u64), String> {
+	let (op, address) = s.split_once('=').ok_or_else(|| format!("`{s}` is not op=address"))?;
+	let address = match address.strip_prefix("0x") {
+		Some(hex) => u64::from_str_radix(hex, 16),
+		None => address.parse(),
+	};
+	Ok((op.parse()?, address.map_err(|err| format!("invalid address in `{s}`: {err}"))?))
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Eip {
+	/// BN254, with the pairing of EIP-197
+	#[value(name = "196")]
+	Eip196,
+	/// BLS12-381
+	#[value(name = "2537")]
+	Eip2537,
+	/// BLS12-377
+	#[value(name = "2539")]
+	Eip2539,
+	/// BW6-761
+	#[value(name = "3026")]
+	Eip3026,
+}
+
+impl Eip {
+	const ALL: [Eip; 4] = [Eip::Eip196, Eip::Eip2537, Eip::Eip2539, Eip::Eip3026];
+
+	/// EIP number, e.g. `2539`.
+	fn name(&self) -> String {
+		self.to_possible_value().expect("no skipped variants").get_name().to_string()
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Set {
+	Success,
+	Fail,
+	All,
+}
+
+impl Set {
+	fn success(&self) -> bool {
+		*self != Set::Fail
+	}
+
+	fn fail(&self) -> bool {
+		*self != Set::Success
+	}
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+	Json,
+	Markdown,
+}
+
+/// Calls the generic command `$f` with the suite of `$eip`.
+macro_rules! dispatch {
+	($eip:expr, $($f:ident)::+, $($arg:expr),*) => {
+		match $eip {
+			Eip::Eip196 => $($f)::+::<Eip196>($($arg),*),
+			Eip::Eip2537 => $($f)::+::<Eip2537>($($arg),*),
+			Eip::Eip2539 => $($f)::+::<Eip2539>($($arg),*),
+			Eip::Eip3026 => $($f)::+::<Eip3026>($($arg),*),
+		}
+	};
+}
+
+fn main() -> ExitCode {
+	let result = match Cli::parse().command {
+		Command::Generate { selection, out } =>
+			dispatch!(selection.eip, cmd::generate, &selection, &out),
+		Command::List { eip } => {
+			for eip in eip.map_or(Eip::ALL.to_vec(), |eip| vec![eip]) {
+				dispatch!(eip, cmd::list, eip);
+			}
+			Ok(())
+		},
+		Command::Verify { selection, dir } =>
+			dispatch!(selection.eip, cmd::verify, &selection, &dir),
+		Command::StateTests { selection, dir, out, network, addresses } => dispatch!(
+			selection.eip,
+			cmd::state_tests,
+			&selection,
+			&addresses,
+			&dir,
+			&out,
+			&network
+		),
+		Command::FoundryTests { selection, dir, out, addresses } =>
+			dispatch!(selection.eip, cmd::foundry_tests, &selection, &addresses, &dir, &out),
+		Command::GasSchedule { benches, curve, mul_gas, pair_gas, format } =>
+			cmd::gas_schedule(&benches, &curve, mul_gas, pair_gas, format),
+	};
+
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(e) => {
+			eprintln!("error: {e}");
+			ExitCode::FAILURE
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, error::Error, fmt::Write, fs, path::Path};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Gas of the calibration benches: ECRECOVER and a single BN254 pairing at Istanbul prices.
+const ECRECOVER: (&str, f64) = ("ECRECOVER/", 3000.0);
+const BN_PAIRING: (&str, f64) = ("Bn/Pairing", 79000.0);
+
+/// A criterion `--message-format=json` message.
+#[derive(Deserialize)]
+struct Message {
+	reason: String,
+	id: Option<String>,
+	typical: Option<Estimate>,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+	estimate: f64,
+	unit: String,
+}
+
+/// Typical time in ns of each completed bench, keyed by bench id.
+pub struct Benches(BTreeMap<String, f64>);
+
+impl Benches {
+	/// Reads the messages of every file, later files overriding benches of earlier ones.
+	pub fn read(paths: &[impl AsRef<Path>]) -> Result<Self> {
+		let mut benches = BTreeMap::new();
+		for path in paths {
+			for line in fs::read_to_string(path)?.lines().filter(|line| !line.trim().is_empty()) {
+				let message: Message = serde_json::from_str(line)?;
+				if message.reason != "benchmark-complete" {
+					continue;
+				}
+				let (Some(id), Some(typical)) = (message.id, message.typical) else {
+					return Err("benchmark-complete message without id or typical time".into());
+				};
+				if typical.unit != "ns" {
+					return Err(format!("bench `{id}` is measured in {}", typical.unit).into());
+				}
+				benches.insert(id, typical.estimate);
+			}
+		}
+		Ok(Benches(benches))
+	}
+
+	/// Last id segment and time of the benches with ids `… for {group}{suffix}/{last}`, e.g.
+	/// `MSM for BW6_761::G1/MSM/8`.
+	fn find<'a>(&'a self, group: &str, suffix: &str) -> impl Iterator<Item = (&'a str, f64)> {
+		let ending = format!(" for {group}{suffix}");
+		self.0.iter().filter_map(move |(id, ns)| {
+			let (prefix, last) = id.rsplit_once('/')?;
+			prefix.ends_with(&ending).then_some((last, *ns))
+		})
+	}
+
+	/// Time of the bench `name`, an error if several benches match.
+	fn get(&self, group: &str, suffix: &str, name: &str) -> Result<Option<f64>> {
+		let mut matches = self.find(group, suffix).filter(|(last, _)| *last == name);
+		match (matches.next(), matches.next()) {
+			(Some(_), Some(_)) => Err(format!("ambiguous bench `{group}{suffix}/{name}`").into()),
+			(bench, _) => Ok(bench.map(|(_, ns)| ns)),
+		}
+	}
+
+	fn require(&self, group: &str, suffix: &str, name: &str) -> Result<f64> {
+		self.get(group, suffix, name)?
+			.ok_or_else(|| format!("missing bench `{group}{suffix}/{name}`").into())
+	}
+
+	/// Bench times of `group{suffix}/k` by pair count `k`, an error if a count repeats.
+	fn by_pairs(&self, group: &str, suffix: &str) -> Result<BTreeMap<usize, f64>> {
+		let mut benches = BTreeMap::new();
+		for (last, ns) in self.find(group, suffix) {
+			if benches.insert(last.parse()?, ns).is_some() {
+				return Err(format!("ambiguous bench `{group}{suffix}/{last}`").into());
+			}
+		}
+		Ok(benches)
+	}
+
+	fn starting_with(&self, prefix: &str) -> Result<f64> {
+		self.0
+			.iter()
+			.find(|(id, _)| id.starts_with(prefix))
+			.map(|(_, ns)| *ns)
+			.ok_or_else(|| format!("missing calibration bench `{prefix}…`").into())
+	}
+}
+
+/// Gas schedule derived from bench times.
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Schedule {
+	/// Gas per second, in millions, the bench times are converted with.
+	pub mgas_per_second: u64,
+	pub g1_add: u64,
+	pub g1_mul: u64,
+	pub g2_add: u64,
+	pub g2_mul: u64,
+	pub pairing_base: u64,
+	pub pairing_per_pair: u64,
+	pub map_g1: Option<u64>,
+	pub map_g2: Option<u64>,
+	/// Mul price the discounts are relative to.
+	pub multiexp_mul_gas: u64,
+	/// G1 multiexp discount of `k` pairs at index `k - 1`, in thousandths.
+	pub multiexp_discount: Vec<u64>,
+}
+
+impl Schedule {
+	/// Derives a schedule from the benches of `curve`, e.g. `BW6_761`. Multiexp discounts are
+	/// relative to `mul_gas`, the measured G1 mul gas if `None`, and the pairing base to
+	/// `pair_gas`, the measured per pair gas if `None`.
+	pub fn derive(
+		benches: &Benches,
+		curve: &str,
+		mul_gas: Option<u64>,
+		pair_gas: Option<u64>,
+	) -> Result<Self> {
+		let (g1, g2) = (format!("{curve}::G1"), format!("{curve}::G2"));
+		// average the throughput the calibration benches run at their current price
+		let rate = |(prefix, gas): (&str, f64)| -> Result<f64> {
+			Ok(gas / benches.starting_with(prefix)? * 1000.0)
+		};
+		let mgas_per_second = ((rate(ECRECOVER)? + rate(BN_PAIRING)?) / 2.0).round();
+		let gas = |ns: f64| ns * mgas_per_second / 1000.0;
+
+		let arithmetic = |group: &str, name: &str| -> Result<u64> {
+			Ok(gas(benches.require(group, "/Arithmetic", name)?).round() as u64)
+		};
+		let g1_mul = arithmetic(&g1, "Scalar Multiplication(worst-case)")?;
+		let multiexp_mul_gas = mul_gas.unwrap_or(g1_mul);
+
+		let msm = benches.by_pairs(&g1, "/MSM")?;
+		if !msm.keys().copied().eq(1..=msm.len()) {
+			return Err("G1 MSM benches must cover 1..=n pairs".into());
+		}
+		let multiexp_discount = msm
+			.iter()
+			.map(|(k, ns)| {
+				(gas(*ns) / (*k as f64 * multiexp_mul_gas as f64) * 1000.0).round() as u64
+			})
+			.collect();
+
+		let pairing: Vec<_> =
+			benches.by_pairs(curve, "/Pairing")?.into_iter().map(|(k, ns)| (k, gas(ns))).collect();
+		let (pairing_base, pairing_per_pair) = average_steps(&pairing, pair_gas)
+			.ok_or("pairing benches must cover at least two pair counts")?;
+
+		let map = |group: &str| -> Result<Option<u64>> {
+			Ok(benches.get(group, "/Arithmetic", "Map to curve")?.map(|ns| gas(ns).round() as u64))
+		};
+		Ok(Schedule {
+			mgas_per_second: mgas_per_second as u64,
+			g1_add: arithmetic(&g1, "Addition")?,
+			g1_mul,
+			g2_add: arithmetic(&g2, "Addition")?,
+			g2_mul: arithmetic(&g2, "Scalar Multiplication(worst-case)")?,
+			pairing_base: pairing_base.round() as u64,
+			pairing_per_pair: pairing_per_pair.round() as u64,
+			map_g1: map(&g1)?,
+			map_g2: map(&g2)?,
+			multiexp_mul_gas,
+			multiexp_discount,
+		})
+	}
+
+	pub fn to_json(&self) -> String {
+		serde_json::to_string_pretty(self).expect("schedule must serialize")
+	}
+
+	pub fn to_markdown(&self) -> String {
+		let mut md = String::from("| Parameter | Gas |\n| --- | --- |\n");
+		let mut row = |name: &str, value: Option<u64>| {
+			if let Some(value) = value {
+				writeln!(md, "| {name} | {value} |").expect("writing to a string must succeed");
+			}
+		};
+		row("MGas/s", Some(self.mgas_per_second));
+		row("G1ADD", Some(self.g1_add));
+		row("G1MUL", Some(self.g1_mul));
+		row("G2ADD", Some(self.g2_add));
+		row("G2MUL", Some(self.g2_mul));
+		row("PAIRING base", Some(self.pairing_base));
+		row("PAIRING per pair", Some(self.pairing_per_pair));
+		row("MAP_FP_TO_G1", self.map_g1);
+		row("MAP_FP2_TO_G2", self.map_g2);
+		row("MULTIEXP mul", Some(self.multiexp_mul_gas));
+
+		md.push_str("\n| k | Discount |\n| --- | --- |\n");
+		for (i, discount) in self.multiexp_discount.iter().enumerate() {
+			writeln!(md, "| {} | {discount} |", i + 1).expect("writing to a string must succeed");
+		}
+		md
+	}
+}
+
+/// `(base, per_pair)` of the pairing averaged as in `benches.md`. `per_pair` is the mean gas
+/// per pair between consecutive pair counts `k`. `base` is the mean over every `k` but the largest
+/// of its gas less `pair_gas`, `per_pair` if `None`, for each pair after the first.
+fn average_steps(points: &[(usize, f64)], pair_gas: Option<u64>) -> Option<(f64, f64)> {
+	let (_, starts) = points.split_last()?;
+	if starts.is_empty() {
+		return None;
+	}
+	let n = starts.len() as f64;
+	let deltas: f64 = starts
+		.iter()
+		.zip(&points[1..])
+		.map(|((k0, gas0), (k1, gas1))| (gas1 - gas0) / (k1 - k0) as f64)
+		.sum();
+	let per_pair = deltas / n;
+	let pair_gas = pair_gas.map_or(per_pair, |gas| gas as f64);
+	let bases: f64 = starts.iter().map(|&(k, gas)| gas - pair_gas * (k as f64 - 1.0)).sum();
+	Some((bases / n, per_pair))
+}
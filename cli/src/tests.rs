@@ -0,0 +1,72 @@
+use crate::{
+	cmd::unknown_files,
+	meter::{Benches, Schedule},
+	parse_address, Addresses,
+};
+use eip_2539_test_gen::Eip2539;
+use eip_3026_test_gen::Eip3026;
+use eip_test_gen_core::{Operation, Suite};
+use std::{env, fs};
+
+/// The committed EIP-3026 benches reproduce the hand computed `benches.md`.
+#[test]
+fn gas_schedule_of_eip_3026() {
+	let benches = Benches::read(&["../eip-3026/gas-meter/benches.json"]).unwrap();
+	let (mul_gas, pair_gas) = (Eip3026::GAS.g1_mul, Eip3026::GAS.pairing_per_pair);
+	let schedule = Schedule::derive(&benches, "BW6_761", Some(mul_gas), Some(pair_gas)).unwrap();
+	assert_eq!(schedule.mgas_per_second, 51);
+	assert_eq!((schedule.g1_add, schedule.g1_mul), (180, 64531));
+	assert_eq!((schedule.pairing_base, schedule.pairing_per_pair), (319906, 120678));
+	assert_eq!(schedule.multiexp_discount, Eip3026::GAS.g1_multiexp_discount);
+}
+
+/// Benches of other curves in the same files are ignored, repeated benches of the curve fail.
+#[test]
+fn gas_schedule_selects_the_curve() {
+	let bench = |id: &str| {
+		let typical = r#"{"estimate":1.0,"unit":"ns"}"#;
+		format!(r#"{{"reason":"benchmark-complete","id":"{id}","typical":{typical}}}"#)
+	};
+	let path = env::temp_dir().join("eip-test-gen-other-curve.json");
+	let read = |ids: &[&str]| {
+		fs::write(&path, ids.iter().map(|id| bench(id) + "\n").collect::<String>()).unwrap();
+		Benches::read(&["../eip-3026/gas-meter/benches.json", path.to_str().unwrap()]).unwrap()
+	};
+	let derive = |benches: &Benches| Schedule::derive(benches, "BW6_761", None, None);
+
+	let other = read(&[
+		"Arithmetic for BLS12_377::G1/Arithmetic/Addition",
+		"Pairing for BLS12_377/Pairing/2",
+	]);
+	let schedule = derive(&other).unwrap();
+	assert_eq!((schedule.g1_add, schedule.pairing_per_pair), (180, 120678));
+	let repeated = read(&["Other for BW6_761::G1/Arithmetic/Addition"]);
+	fs::remove_file(&path).unwrap();
+	let error = derive(&repeated).err().unwrap();
+	assert!(error.to_string().contains("ambiguous"), "{error}");
+}
+
+/// Leftovers of renamed operations fail verify, other files are ignored.
+#[test]
+fn unknown_files_are_reported() {
+	let dir = env::temp_dir().join("eip-test-gen-unknown-files");
+	fs::create_dir_all(&dir).unwrap();
+	let known = ["bls12377G1Add.json", "metadata-bls12377.json"];
+	for name in known.into_iter().chain(["bls12377G1Add_old.json", "notes.md"]) {
+		fs::write(dir.join(name), "[]").unwrap();
+	}
+	let unknown = unknown_files::<Eip2539>(&dir).unwrap();
+	fs::remove_dir_all(&dir).unwrap();
+	assert_eq!(unknown, [dir.join("bls12377G1Add_old.json")]);
+}
+
+/// The last override of an operation wins, the other operations keep the address of the EIP.
+#[test]
+fn address_overrides() {
+	let overrides = ["g1add=0x0a", "g1-add=12"].map(|s| parse_address(s).unwrap());
+	let addresses = Addresses { overrides: overrides.to_vec() };
+	assert_eq!(addresses.get::<Eip2539>(Operation::G1Add), Some(12));
+	assert_eq!(addresses.get::<Eip2539>(Operation::G2Add), Eip2539::address(Operation::G2Add));
+	assert!(parse_address("g1add").is_err());
+	assert!(parse_address("g1add=0xzz").is_err());
+}
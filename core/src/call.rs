@@ -0,0 +1,49 @@
+use crate::{
+	operation::Operation,
+	suite::Suite,
+	vector::{VectorFail, VectorSuccess},
+};
+use std::collections::HashSet;
+
+/// A call to the precompile of a vector, as the exported tests make it.
+pub struct PrecompileCall {
+	pub name: String,
+	/// Hex encoded input of the precompile.
+	pub input: String,
+	/// Gas the call forwards to the precompile.
+	pub gas: u64,
+	/// Hex encoded output, `None` if the precompile must fail.
+	pub output: Option<String>,
+}
+
+/// Rejects calls sharing a name, which the exported tests use as identifiers.
+pub fn check_unique_names(calls: &[PrecompileCall]) -> Result<(), String> {
+	let mut names = HashSet::new();
+	match calls.iter().find(|call| !names.insert(call.name.as_str())) {
+		Some(call) => Err(format!("vector name `{}` is not unique", call.name)),
+		None => Ok(()),
+	}
+}
+
+impl PrecompileCall {
+	/// Calls with exactly [`VectorSuccess::gas`], so the precompile must not charge more.
+	pub fn success(vector: &VectorSuccess) -> Self {
+		PrecompileCall {
+			name: vector.name.clone(),
+			input: vector.input.clone(),
+			gas: vector.gas,
+			output: Some(vector.expected.clone()),
+		}
+	}
+
+	/// Calls with the gas of the input length, so the precompile fails on the input. The EVM
+	/// only sees that the call fails, not the [`VectorFail::expected_error`].
+	pub fn fail<S: Suite>(op: Operation, vector: &VectorFail) -> Self {
+		PrecompileCall {
+			name: vector.name.clone(),
+			input: vector.input.clone(),
+			gas: op.gas::<S>(vector.input.len() / 2).max(1),
+			output: None,
+		}
+	}
+}
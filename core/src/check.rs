@@ -0,0 +1,32 @@
+use crate::{
+	config::{Config, Sizes},
+	rng::DEFAULT_SEED,
+	suite::{Suite, G1, G2},
+};
+use ark_ec::Group;
+
+/// Asserts that a suite agrees with its own vectors: the generators encode to
+/// [`Suite::g1_size`] and [`Suite::g2_size`] bytes, every operation has failure vectors and the
+/// reference precompiles reproduce the success and failure vectors of a small corpus.
+pub fn check_reference<S: Suite>() {
+	assert_eq!(S::encode_g1(G1::<S>::generator()).len(), S::g1_size());
+	assert_eq!(S::encode_g2(G2::<S>::generator()).len(), S::g2_size());
+	let config = Config { count: 4, sizes: Sizes::List(vec![1, 2]) };
+	for &op in S::OPERATIONS {
+		for vector in op.gen_vectors::<S>(DEFAULT_SEED, &config) {
+			let output = op.execute::<S>(&hex::decode(&vector.input).unwrap());
+			assert_eq!(output.map(hex::encode), Ok(vector.expected), "{op} {}", vector.name);
+		}
+		let fail_vectors = op.gen_fail_vectors::<S>(DEFAULT_SEED);
+		assert!(!fail_vectors.is_empty(), "{op}");
+		for vector in fail_vectors {
+			let output = op.execute::<S>(&hex::decode(&vector.input).unwrap());
+			assert_eq!(
+				output.map_err(|err| S::error_message(err).to_string()),
+				Err(vector.expected_error),
+				"{op} {}",
+				vector.name
+			);
+		}
+	}
+}
@@ -0,0 +1,94 @@
+use crate::{operation::Operation, NUM_TESTS};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// Pair counts a multiexp or pairing corpus is generated for.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Sizes {
+	/// Every `step`th pair count of `min..=max`, written `min..=max` or `min..=max:step`.
+	Range { min: usize, max: usize, step: usize },
+	/// Explicit pair counts, written `1,2,4,8`.
+	List(Vec<usize>),
+}
+
+impl Sizes {
+	pub fn to_vec(&self) -> Vec<usize> {
+		match self {
+			Sizes::Range { min, max, step } => (*min..=*max).step_by(*step).collect(),
+			Sizes::List(sizes) => sizes.clone(),
+		}
+	}
+}
+
+impl fmt::Display for Sizes {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Sizes::Range { min, max, step: 1 } => write!(f, "{min}..={max}"),
+			Sizes::Range { min, max, step } => write!(f, "{min}..={max}:{step}"),
+			Sizes::List(sizes) => {
+				let sizes: Vec<String> = sizes.iter().map(usize::to_string).collect();
+				f.write_str(&sizes.join(","))
+			},
+		}
+	}
+}
+
+impl FromStr for Sizes {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let parse =
+			|n: &str| n.trim().parse::<usize>().map_err(|_| format!("invalid pair count `{n}`"));
+		let sizes = match s.split_once("..=") {
+			Some((min, rest)) => {
+				let (max, step) = rest.split_once(':').unwrap_or((rest, "1"));
+				let (min, max, step) = (parse(min)?, parse(max)?, parse(step)?);
+				if min > max || step == 0 {
+					return Err(format!("invalid pair count range `{s}`"));
+				}
+				Sizes::Range { min, max, step }
+			},
+			None => Sizes::List(s.split(',').map(parse).collect::<Result<_, _>>()?),
+		};
+		if sizes.to_vec().contains(&0) {
+			return Err(format!("pair counts must be positive in `{s}`"));
+		}
+		Ok(sizes)
+	}
+}
+
+impl From<Sizes> for String {
+	fn from(sizes: Sizes) -> Self {
+		sizes.to_string()
+	}
+}
+
+impl TryFrom<String> for Sizes {
+	type Error = String;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> {
+		s.parse()
+	}
+}
+
+/// How many random vectors an operation generates on top of its edge cases. `count` applies to
+/// add and mul, `sizes` to multiexp and pairing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Config {
+	pub count: usize,
+	pub sizes: Sizes,
+}
+
+impl Config {
+	/// Corpus size of the original generator: 100 vectors, multiexp of 1..=100 pairs and pairing of
+	/// 2..=101 pairs.
+	pub fn default_for(op: Operation) -> Self {
+		let (min, max) = match op {
+			Operation::Pairing => (2, NUM_TESTS + 1),
+			_ => (1, NUM_TESTS),
+		};
+		Config { count: NUM_TESTS, sizes: Sizes::Range { min, max, step: 1 } }
+	}
+}
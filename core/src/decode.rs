@@ -0,0 +1,80 @@
+use crate::{encode::encode_fe, error::InputError};
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ff::{Field, PrimeField, Zero};
+
+/// Decodes a big-endian word of a base prime field element, rejecting nonzero top padding and
+/// values not below the modulus.
+pub fn decode_fe<F: PrimeField>(word: &[u8], fe_size: usize) -> Result<F, InputError> {
+	if word.len() < fe_size {
+		return Err(InputError::InvalidLength);
+	}
+	let (padding, value) = word.split_at(word.len() - fe_size);
+	if padding.iter().any(|b| *b != 0) {
+		return Err(InputError::InvalidFieldElementTopBytes);
+	}
+	let fe = F::from_be_bytes_mod_order(value);
+	// non-canonical values are reduced, so they do not round trip
+	if encode_fe(fe, fe_size) != value {
+		return Err(InputError::InvalidFieldElement);
+	}
+	Ok(fe)
+}
+
+/// Decodes `bytes` word by word, extension field elements as `c0 || c1`.
+pub fn decode_field<F: Field>(
+	bytes: &[u8],
+	word_size: usize,
+	fe_size: usize,
+) -> Result<F, InputError> {
+	if bytes.len() != F::extension_degree() as usize * word_size {
+		return Err(InputError::InvalidLength);
+	}
+	let elems = bytes
+		.chunks_exact(word_size)
+		.map(|word| decode_fe(word, fe_size))
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(F::from_base_prime_field_elems(&elems).expect("word count must match the extension degree"))
+}
+
+/// Decodes the affine coordinates of a point encoded by [`encode_point`](crate::encode_point),
+/// all zeros being the point at infinity. The subgroup is not checked.
+pub fn decode_point<P: SWCurveConfig>(
+	bytes: &[u8],
+	word_size: usize,
+	fe_size: usize,
+) -> Result<Projective<P>, InputError> {
+	let (x, y) = bytes.split_at(bytes.len() / 2);
+	let x: P::BaseField = decode_field(x, word_size, fe_size)?;
+	let y: P::BaseField = decode_field(y, word_size, fe_size)?;
+	if x.is_zero() && y.is_zero() {
+		return Ok(Projective::<P>::default());
+	}
+	let p = Affine::<P>::new_unchecked(x, y);
+	if !p.is_on_curve() {
+		return Err(InputError::PointNotOnCurve);
+	}
+	Ok(p.into())
+}
+
+/// `p` if it lies in the prime order subgroup, `error` otherwise.
+pub fn check_subgroup<P: SWCurveConfig>(
+	p: Projective<P>,
+	error: InputError,
+) -> Result<Projective<P>, InputError> {
+	match Affine::<P>::from(p).is_in_correct_subgroup_assuming_on_curve() {
+		true => Ok(p),
+		false => Err(error),
+	}
+}
+
+/// Little-endian limbs of the big-endian integer `bytes`, taken as is without reduction.
+pub fn decode_scalar(bytes: &[u8]) -> Vec<u64> {
+	bytes
+		.rchunks(8)
+		.map(|chunk| {
+			let mut limb = [0u8; 8];
+			limb[8 - chunk.len()..].copy_from_slice(chunk);
+			u64::from_be_bytes(limb)
+		})
+		.collect()
+}
@@ -0,0 +1,40 @@
+use crate::suite::{Fq, Fr, Suite};
+use ark_ec::{
+	short_weierstrass::{Projective, SWCurveConfig},
+	AffineRepr, CurveGroup,
+};
+use ark_ff::{BigInteger, Field, PrimeField};
+
+/// Big-endian encoding of `fe` left padded with zeros to `size` bytes.
+pub fn encode_fe<F: PrimeField>(fe: F, size: usize) -> Vec<u8> {
+	let rep = fe.into_bigint().to_bytes_be();
+	let mut result = vec![0u8; size];
+	result[size - rep.len()..].copy_from_slice(&rep);
+	result
+}
+
+pub fn encode_fq<S: Suite>(field: Fq<S>) -> Vec<u8> {
+	encode_fe(field, S::WORD_SIZE)
+}
+
+pub fn encode_fr<S: Suite>(r: Fr<S>) -> Vec<u8> {
+	encode_fe(r, S::SCALAR_SIZE)
+}
+
+/// Encodes `fe` word by word, extension field elements as `c0 || c1`.
+pub fn encode_field<F: Field>(fe: F, word_size: usize) -> Vec<u8> {
+	fe.to_base_prime_field_elements().flat_map(|fe| encode_fe(fe, word_size)).collect()
+}
+
+/// Encodes the affine coordinates of `p` word by word, extension field coordinates as `c0 || c1`.
+/// The point at infinity is encoded as all zeros.
+pub fn encode_point<P: SWCurveConfig>(p: Projective<P>, word_size: usize) -> Vec<u8> {
+	let g = p.into_affine();
+	let (x, y) = match g.xy() {
+		Some((x, y)) => (*x, *y),
+		None => (P::BaseField::ZERO, P::BaseField::ZERO),
+	};
+	let mut result = encode_field(x, word_size);
+	result.extend(encode_field(y, word_size));
+	result
+}
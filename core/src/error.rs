@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Why a precompile rejects its input. Displays as the `expected_error` of the failure vectors
+/// unless [`Suite::error_message`](crate::Suite::error_message) words it differently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputError {
+	/// The input is not of a length the precompile accepts.
+	InvalidLength,
+	/// A field element word has nonzero top padding.
+	InvalidFieldElementTopBytes,
+	/// A field element is not below the modulus.
+	InvalidFieldElement,
+	PointNotOnCurve,
+	G1PointNotInSubgroup,
+	G2PointNotInSubgroup,
+}
+
+impl InputError {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			InputError::InvalidLength => "invalid input length",
+			InputError::InvalidFieldElementTopBytes => "invalid Fq",
+			InputError::InvalidFieldElement => "invalid Fq",
+			InputError::PointNotOnCurve => "point is not on curve",
+			InputError::G1PointNotInSubgroup => "g1 point is not on correct subgroup",
+			InputError::G2PointNotInSubgroup => "g2 point is not on correct subgroup",
+		}
+	}
+}
+
+impl fmt::Display for InputError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl std::error::Error for InputError {}
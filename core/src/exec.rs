@@ -0,0 +1,79 @@
+use crate::{
+	decode::{decode_field, decode_scalar},
+	error::InputError,
+	suite::Suite,
+};
+use ark_ec::{
+	pairing::Pairing,
+	short_weierstrass::{Projective, SWCurveConfig},
+	Group,
+};
+use ark_ff::Zero;
+
+pub type Decode<P> = fn(&[u8]) -> Result<Projective<P>, InputError>;
+type Encode<P> = fn(Projective<P>) -> Vec<u8>;
+
+// The reference precompiles take input of a length `Operation::input_length` accepts, already
+// padded or cut to size.
+
+pub fn execute_add<P: SWCurveConfig>(
+	input: &[u8],
+	point_size: usize,
+	decode: Decode<P>,
+	encode: Encode<P>,
+) -> Result<Vec<u8>, InputError> {
+	let (a, b) = input.split_at(point_size);
+	Ok(encode(decode(a)? + decode(b)?))
+}
+
+/// Multiplies by the scalar as a plain integer, so scalars of at least the group order are valid.
+pub fn execute_mul<P: SWCurveConfig>(
+	input: &[u8],
+	point_size: usize,
+	decode: Decode<P>,
+	encode: Encode<P>,
+) -> Result<Vec<u8>, InputError> {
+	let (p, e) = input.split_at(point_size);
+	Ok(encode(decode(p)?.mul_bigint(decode_scalar(e))))
+}
+
+pub fn execute_multiexp<S: Suite, P: SWCurveConfig>(
+	input: &[u8],
+	point_size: usize,
+	decode: Decode<P>,
+	encode: Encode<P>,
+) -> Result<Vec<u8>, InputError> {
+	let mut r = Projective::<P>::zero();
+	for pair in input.chunks_exact(point_size + S::SCALAR_SIZE) {
+		let (p, e) = pair.split_at(point_size);
+		r += decode(p)?.mul_bigint(decode_scalar(e));
+	}
+	Ok(encode(r))
+}
+
+/// Outputs a 32 byte word holding `1` if the product of the pairings is one, `0` otherwise.
+pub fn execute_pairing<S: Suite>(
+	input: &[u8],
+	decode_g1: Decode<S::G1Config>,
+	decode_g2: Decode<S::G2Config>,
+) -> Result<Vec<u8>, InputError> {
+	let mut g1s = vec![];
+	let mut g2s = vec![];
+	for pair in input.chunks_exact(S::g1_size() + S::g2_size()) {
+		let (a, b) = pair.split_at(S::g1_size());
+		g1s.push(decode_g1(a)?);
+		g2s.push(decode_g2(b)?);
+	}
+	let mut result = vec![0u8; 32];
+	result[31] = u8::from(S::Engine::multi_pairing(g1s, g2s).is_zero());
+	Ok(result)
+}
+
+pub fn execute_map<S: Suite, P: SWCurveConfig>(
+	input: &[u8],
+	map: fn(P::BaseField) -> Option<Projective<P>>,
+	encode: Encode<P>,
+) -> Result<Vec<u8>, InputError> {
+	let fe = decode_field(input, S::WORD_SIZE, S::FE_SIZE)?;
+	Ok(encode(map(fe).expect("suite must specify the map")))
+}
@@ -0,0 +1,841 @@
+use crate::{
+	encode::{encode_field, encode_fr},
+	error::InputError,
+	operation::{InputLength, Operation},
+	rng::{seeded_rng, TestRng},
+	suite::{Fq, Fr, Suite, G1, G2},
+	vector::VectorFail,
+};
+use ark_ec::{
+	short_weierstrass::{Projective, SWCurveConfig},
+	CurveConfig,
+};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ark_std::UniformRand;
+
+/// Draws an invalid point.
+type RandPoint<P> = fn(&mut TestRng) -> Projective<P>;
+
+/// Invalid length and padding vectors of an operation accepting `length`, `input_len` being one of
+/// its valid lengths.
+fn gen_fail_vectors<S: Suite>(length: InputLength, input_len: usize) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = vec![];
+
+	// invalid length: empty, a byte or a word short or long, half a pair short
+	let mut lengths = vec![
+		(0, "empty"),
+		(input_len - 1, "short"),
+		(input_len + 1, "large"),
+		(input_len - S::WORD_SIZE, "short_word"),
+		(input_len + S::WORD_SIZE, "long_word"),
+	];
+	if let InputLength::Pairs { pair_size, .. } = length {
+		lengths.push((input_len - pair_size / 2, "misaligned"));
+	}
+	let mut seen = vec![];
+	for (len, case) in lengths {
+		// a word may be all of the input
+		if length.accepts(len) || seen.contains(&len) {
+			continue;
+		}
+		seen.push(len);
+		let vector = VectorFail {
+			input: hex::encode(vec![1u8; len]),
+			expected_error: S::error_message(InputError::InvalidLength).to_string(),
+			name: format!("invalid_input_length_{case}"),
+		};
+		vectors.push(vector);
+	}
+
+	// violate top zeros
+	if S::WORD_SIZE > S::FE_SIZE {
+		let input: String = hex::encode(vec![1u8; input_len]);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::InvalidFieldElementTopBytes).to_string(),
+			name: String::from("violate_top_zero_bytes"),
+		};
+		vectors.push(vector);
+	}
+
+	vectors
+}
+
+/// A padded field word holding `p + n`.
+fn modulus_plus<S: Suite>(n: u64) -> Vec<u8> {
+	let mut m = Fq::<S>::MODULUS;
+	m.add_with_carry(&n.into());
+	let rep = m.to_bytes_be();
+	let mut result = vec![0u8; S::WORD_SIZE];
+	result[S::WORD_SIZE - rep.len()..].copy_from_slice(&rep);
+	result
+}
+
+/// Padded field words holding `p`, `p + 1` and the largest value of an unpadded word, e.g.
+/// `2^384 - 1` for 48 byte field elements.
+fn non_canonical_words<S: Suite>() -> [(Vec<u8>, &'static str); 3] {
+	let mut max = vec![0u8; S::WORD_SIZE];
+	max[S::WORD_SIZE - S::FE_SIZE..].fill(0xff);
+	[(modulus_plus::<S>(0), "modulus"), (modulus_plus::<S>(1), "modulus_plus_one"), (max, "max")]
+}
+
+/// Sets a single top padding byte, the first, a middle and the last one, of each of the `words`
+/// of the valid `input` in turn.
+fn gen_padding_vectors<S: Suite>(input: &[u8], words: &[(usize, String)]) -> Vec<VectorFail> {
+	let padding = S::WORD_SIZE - S::FE_SIZE;
+	let mut vectors: Vec<VectorFail> = vec![];
+	if padding == 0 {
+		return vectors;
+	}
+	for (offset, word) in words {
+		for (i, position) in [(0, "first"), (padding / 2, "middle"), (padding - 1, "last")] {
+			let mut input_bytes = input.to_vec();
+			input_bytes[offset + i] = 1;
+			let error = InputError::InvalidFieldElementTopBytes;
+			let vector = VectorFail {
+				input: hex::encode(input_bytes),
+				expected_error: S::error_message(error).to_string(),
+				name: format!("top_padding_{position}_{word}"),
+			};
+			vectors.push(vector);
+		}
+	}
+	vectors
+}
+
+/// Offsets and names of the coordinate words of a point encoded at `offset`, e.g. `a_x1` for the
+/// second word of the x coordinate of `a`.
+fn point_words<S: Suite, P: SWCurveConfig>(slot: &str, offset: usize) -> Vec<(usize, String)> {
+	let degree = P::BaseField::extension_degree() as usize;
+	(0..2 * degree)
+		.map(|i| {
+			let coordinate = if i < degree { "x" } else { "y" };
+			let name = match degree {
+				1 => format!("{slot}_{coordinate}"),
+				_ => format!("{slot}_{coordinate}{}", i % degree),
+			};
+			(offset + i * S::WORD_SIZE, name)
+		})
+		.collect()
+}
+
+/// Puts each non-canonical value into each of the `words` of the valid `input` in turn.
+fn gen_non_canonical_vectors<S: Suite>(input: &[u8], words: &[(usize, String)]) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = vec![];
+	for (offset, word) in words {
+		for (value, value_name) in non_canonical_words::<S>() {
+			let mut input_bytes = input.to_vec();
+			input_bytes[*offset..offset + S::WORD_SIZE].copy_from_slice(&value);
+			let vector = VectorFail {
+				input: hex::encode(input_bytes),
+				expected_error: S::error_message(InputError::InvalidFieldElement).to_string(),
+				name: format!("non_canonical_{value_name}_{word}"),
+			};
+			vectors.push(vector);
+		}
+	}
+	vectors
+}
+
+fn gen_fail_add_vectors<S: Suite, P: SWCurveConfig>(
+	rng: &mut TestRng,
+	length: InputLength,
+	point_size: usize,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_on_curve: fn(&mut TestRng) -> Projective<P>,
+) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = gen_fail_vectors::<S>(length, 2 * point_size);
+
+	// large modulus
+	{
+		let a = Projective::<P>::rand(rng);
+
+		let mut input_bytes: Vec<u8> = vec![];
+		input_bytes.extend(encode(a));
+		input_bytes.extend(modulus_plus::<S>(1));
+		input_bytes.extend(vec![0u8; point_size - S::WORD_SIZE]);
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::InvalidFieldElement).to_string(),
+			name: String::from("large_field_element"),
+		};
+		vectors.push(vector);
+	}
+
+	// not on curve
+	{
+		let a = Projective::<P>::rand(rng);
+		let b = not_on_curve(rng);
+
+		let mut input_bytes: Vec<u8> = vec![];
+		input_bytes.extend(encode(a));
+		input_bytes.extend(encode(b));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::PointNotOnCurve).to_string(),
+			name: String::from("point_not_on_curve"),
+		};
+		vectors.push(vector);
+	}
+
+	// non-canonical coordinates and padding of either operand
+	{
+		let a = Projective::<P>::rand(rng);
+		let b = Projective::<P>::rand(rng);
+		let mut input_bytes = encode(a);
+		input_bytes.extend(encode(b));
+
+		let mut words = point_words::<S, P>("a", 0);
+		words.extend(point_words::<S, P>("b", point_size));
+		vectors.extend(gen_non_canonical_vectors::<S>(&input_bytes, &words));
+		vectors.extend(gen_padding_vectors::<S>(&input_bytes, &words));
+	}
+	vectors
+}
+
+fn gen_fail_mul_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	length: InputLength,
+	point_size: usize,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_on_curve: fn(&mut TestRng) -> Projective<P>,
+) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = gen_fail_vectors::<S>(length, point_size + S::SCALAR_SIZE);
+
+	// large modulus
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+		// x
+		input_bytes.extend(modulus_plus::<S>(1));
+		// y
+		input_bytes.extend(vec![0u8; point_size - S::WORD_SIZE]);
+		// e
+		input_bytes.extend(vec![0u8; S::SCALAR_SIZE]);
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::InvalidFieldElement).to_string(),
+			name: String::from("large_field_element"),
+		};
+		vectors.push(vector);
+	}
+
+	// not on curve
+	{
+		let a = not_on_curve(rng);
+
+		let mut input_bytes: Vec<u8> = vec![];
+		input_bytes.extend(encode(a));
+		input_bytes.extend(vec![0u8; S::SCALAR_SIZE]);
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::PointNotOnCurve).to_string(),
+			name: String::from("point_not_on_curve"),
+		};
+		vectors.push(vector);
+	}
+
+	// non-canonical coordinates and padding
+	{
+		let a = Projective::<P>::rand(rng);
+		let e = Fr::<S>::rand(rng);
+		let mut input_bytes = encode(a);
+		input_bytes.extend(encode_fr::<S>(e));
+
+		let words = point_words::<S, P>("p", 0);
+		vectors.extend(gen_non_canonical_vectors::<S>(&input_bytes, &words));
+		vectors.extend(gen_padding_vectors::<S>(&input_bytes, &words));
+	}
+	vectors
+}
+
+fn gen_fail_multiexp_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	length: InputLength,
+	point_size: usize,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_on_curve: fn(&mut TestRng) -> Projective<P>,
+) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> =
+		gen_fail_vectors::<S>(length, 3 * (point_size + S::SCALAR_SIZE));
+
+	// large modulus
+	{
+		let a = Projective::<P>::rand(rng);
+		let b = Projective::<P>::rand(rng);
+		let e1 = Fr::<S>::rand(rng);
+		let e2 = Fr::<S>::rand(rng);
+
+		let mut input_bytes: Vec<u8> = vec![];
+		input_bytes.extend(encode(a));
+		input_bytes.extend(encode_fr::<S>(e1));
+		input_bytes.extend(encode(b));
+		input_bytes.extend(encode_fr::<S>(e2));
+
+		// x
+		input_bytes.extend(modulus_plus::<S>(1));
+		// y
+		input_bytes.extend(vec![0u8; point_size - S::WORD_SIZE]);
+		// e
+		input_bytes.extend(vec![0u8; S::SCALAR_SIZE]);
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::InvalidFieldElement).to_string(),
+			name: String::from("large_field_element"),
+		};
+		vectors.push(vector);
+	}
+
+	// not on curve
+	{
+		let a = Projective::<P>::rand(rng);
+		let b = Projective::<P>::rand(rng);
+		let c = not_on_curve(rng);
+		let e1 = Fr::<S>::rand(rng);
+		let e2 = Fr::<S>::rand(rng);
+		let e3 = Fr::<S>::rand(rng);
+
+		let mut input_bytes: Vec<u8> = vec![];
+		input_bytes.extend(encode(a));
+		input_bytes.extend(encode_fr::<S>(e1));
+		input_bytes.extend(encode(b));
+		input_bytes.extend(encode_fr::<S>(e2));
+		input_bytes.extend(encode(c));
+		input_bytes.extend(encode_fr::<S>(e3));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::PointNotOnCurve).to_string(),
+			name: String::from("point_not_on_curve"),
+		};
+		vectors.push(vector);
+	}
+
+	// non-canonical coordinates and padding of the point of either pair
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+		let mut words = vec![];
+		for i in 0..2 {
+			words.extend(point_words::<S, P>(&format!("pair{i}"), input_bytes.len()));
+			input_bytes.extend(encode(Projective::<P>::rand(rng)));
+			input_bytes.extend(encode_fr::<S>(Fr::<S>::rand(rng)));
+		}
+		vectors.extend(gen_non_canonical_vectors::<S>(&input_bytes, &words));
+		vectors.extend(gen_padding_vectors::<S>(&input_bytes, &words));
+	}
+	vectors
+}
+
+/// Points outside of the subgroup at each of `pairs` (point, scalar) pairs, if `op` checks the
+/// subgroup and the curve has points outside of it.
+fn gen_fail_subgroup_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	op: Operation,
+	pairs: usize,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_in_subgroup: fn(&mut TestRng) -> Projective<P>,
+	error: InputError,
+) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = vec![];
+	let Some((not_in_subgroup, error)) = subgroup_check::<S, P>(op, not_in_subgroup, error) else {
+		return vectors;
+	};
+	for i in 0..pairs {
+		let mut input_bytes: Vec<u8> = vec![];
+		for j in 0..pairs {
+			let a = if i == j { not_in_subgroup(rng) } else { Projective::<P>::rand(rng) };
+			input_bytes.extend(encode(a));
+			input_bytes.extend(encode_fr::<S>(Fr::<S>::rand(rng)));
+		}
+
+		let input: String = hex::encode(input_bytes);
+		let name = match pairs {
+			1 => String::from("incorrect_subgroup"),
+			_ => format!("incorrect_subgroup_pair{i}"),
+		};
+		let expected_error = S::error_message(error).to_string();
+		let vector = VectorFail { input, expected_error, name };
+		vectors.push(vector);
+	}
+	vectors
+}
+
+/// `not_in_subgroup` and the error rejecting its points, if `op` checks the subgroup and the curve
+/// has points outside of it.
+fn subgroup_check<S: Suite, P: SWCurveConfig>(
+	op: Operation,
+	not_in_subgroup: RandPoint<P>,
+	error: InputError,
+) -> Option<(RandPoint<P>, InputError)> {
+	(op.checks_subgroup::<S>() && P::COFACTOR != [1]).then_some((not_in_subgroup, error))
+}
+
+/// Largest pair count of the vectors failing at a given pair.
+const INDEXED_PAIRS: usize = 4;
+
+/// A point of each pair and the invalid points it may be replaced with.
+struct PairSlot<P: SWCurveConfig> {
+	/// Appended to the failure class in vector names, e.g. `_g2`.
+	suffix: &'static str,
+	/// Offset of the point within the pair.
+	offset: usize,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_on_curve: RandPoint<P>,
+	/// Points outside of the subgroup and their error, if the operation rejects them.
+	not_in_subgroup: Option<(RandPoint<P>, InputError)>,
+}
+
+/// For 1 to [`INDEXED_PAIRS`] valid pairs drawn by `pair`, makes the point of `slot` at the first,
+/// middle and last pair in turn non-canonical, off the curve, outside of the subgroup and top
+/// padded, e.g. `not_on_curve_g2_pair1_of_3`.
+fn gen_pair_index_vectors<S: Suite, P: SWCurveConfig>(
+	rng: &mut TestRng,
+	pair: impl Fn(&mut TestRng) -> Vec<u8>,
+	slot: PairSlot<P>,
+) -> Vec<VectorFail> {
+	let mut vectors: Vec<VectorFail> = vec![];
+	let PairSlot { suffix, offset, encode, not_on_curve, not_in_subgroup } = slot;
+	let point_size = encode(Projective::<P>::zero()).len();
+	let [(modulus, _), ..] = non_canonical_words::<S>();
+
+	// each class corrupts the encoded point in place
+	type Corrupt<'a> = Box<dyn Fn(&mut TestRng, &mut [u8]) + 'a>;
+	let mut classes: Vec<(&str, InputError, Corrupt)> = vec![
+		(
+			"non_canonical",
+			InputError::InvalidFieldElement,
+			Box::new(|_, point| point[..S::WORD_SIZE].copy_from_slice(&modulus)),
+		),
+		(
+			"not_on_curve",
+			InputError::PointNotOnCurve,
+			Box::new(|rng, point| point.copy_from_slice(&encode(not_on_curve(rng)))),
+		),
+	];
+	if let Some((not_in_subgroup, error)) = not_in_subgroup {
+		classes.push((
+			"incorrect_subgroup",
+			error,
+			Box::new(move |rng, point| point.copy_from_slice(&encode(not_in_subgroup(rng)))),
+		));
+	}
+	if S::WORD_SIZE > S::FE_SIZE {
+		classes.push((
+			"padding_violation",
+			InputError::InvalidFieldElementTopBytes,
+			Box::new(|_, point| point[0] = 1),
+		));
+	}
+
+	for n in 1..=INDEXED_PAIRS {
+		let mut indices = vec![0, n / 2, n - 1];
+		indices.dedup();
+		for index in indices {
+			for (class, error, corrupt) in &classes {
+				let pairs: Vec<Vec<u8>> = (0..n).map(|_| pair(rng)).collect();
+				let start = pairs[..index].iter().map(Vec::len).sum::<usize>() + offset;
+				let mut input_bytes = pairs.concat();
+				corrupt(rng, &mut input_bytes[start..start + point_size]);
+
+				let vector = VectorFail {
+					input: hex::encode(input_bytes),
+					expected_error: S::error_message(*error).to_string(),
+					name: format!("{class}{suffix}_pair{index}_of_{n}"),
+				};
+				vectors.push(vector);
+			}
+		}
+	}
+	vectors
+}
+
+fn gen_fail_map_vectors<S: Suite, F: Field>(
+	rng: &mut TestRng,
+	length: InputLength,
+) -> Vec<VectorFail> {
+	let degree = F::extension_degree() as usize;
+	let mut vectors: Vec<VectorFail> = gen_fail_vectors::<S>(length, degree * S::WORD_SIZE);
+
+	// large modulus, in each coordinate of an extension element
+	for i in 0..degree {
+		let mut input_bytes = encode_field(F::rand(rng), S::WORD_SIZE);
+		input_bytes[i * S::WORD_SIZE..(i + 1) * S::WORD_SIZE]
+			.copy_from_slice(&modulus_plus::<S>(1));
+
+		let input: String = hex::encode(input_bytes);
+		let name = match degree {
+			1 => String::from("large_field_element"),
+			_ => format!("large_field_element_c{i}"),
+		};
+		let expected_error = S::error_message(InputError::InvalidFieldElement).to_string();
+		let vector = VectorFail { input, expected_error, name };
+		vectors.push(vector);
+	}
+
+	// every non-canonical value and padding byte in each coordinate
+	{
+		let input_bytes = encode_field(F::rand(rng), S::WORD_SIZE);
+		let words: Vec<(usize, String)> = (0..degree)
+			.map(|i| match degree {
+				1 => (0, String::from("fe")),
+				_ => (i * S::WORD_SIZE, format!("c{i}")),
+			})
+			.collect();
+		vectors.extend(gen_non_canonical_vectors::<S>(&input_bytes, &words));
+		vectors.extend(gen_padding_vectors::<S>(&input_bytes, &words));
+	}
+	vectors
+}
+
+pub fn gen_fail_g1_add_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	gen_fail_add_vectors::<S, S::G1Config>(
+		&mut seeded_rng(seed),
+		Operation::G1Add.input_length::<S>(),
+		S::g1_size(),
+		S::encode_g1,
+		S::rand_g1_point_not_on_curve,
+	)
+}
+
+pub fn gen_fail_g1_mul_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_fail_mul_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1Mul.input_length::<S>(),
+		S::g1_size(),
+		S::encode_g1,
+		S::rand_g1_point_not_on_curve,
+	);
+	vectors.extend(gen_fail_subgroup_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1Mul,
+		1,
+		S::encode_g1,
+		S::rand_g1_point_not_on_correct_subgroup,
+		InputError::G1PointNotInSubgroup,
+	));
+	vectors
+}
+
+pub fn gen_fail_g1_multiexp_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_fail_multiexp_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1MultiExp.input_length::<S>(),
+		S::g1_size(),
+		S::encode_g1,
+		S::rand_g1_point_not_on_curve,
+	);
+	vectors.extend(gen_fail_subgroup_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1MultiExp,
+		3,
+		S::encode_g1,
+		S::rand_g1_point_not_on_correct_subgroup,
+		InputError::G1PointNotInSubgroup,
+	));
+	let pair = |rng: &mut TestRng| {
+		let mut pair = S::encode_g1(G1::<S>::rand(rng));
+		pair.extend(encode_fr::<S>(Fr::<S>::rand(rng)));
+		pair
+	};
+	let slot = PairSlot {
+		suffix: "",
+		offset: 0,
+		encode: S::encode_g1,
+		not_on_curve: S::rand_g1_point_not_on_curve,
+		not_in_subgroup: subgroup_check::<S, S::G1Config>(
+			Operation::G1MultiExp,
+			S::rand_g1_point_not_on_correct_subgroup,
+			InputError::G1PointNotInSubgroup,
+		),
+	};
+	vectors.extend(gen_pair_index_vectors::<S, S::G1Config>(rng, pair, slot));
+	vectors
+}
+
+pub fn gen_fail_g2_add_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	gen_fail_add_vectors::<S, S::G2Config>(
+		&mut seeded_rng(seed),
+		Operation::G2Add.input_length::<S>(),
+		S::g2_size(),
+		S::encode_g2,
+		S::rand_g2_point_not_on_curve,
+	)
+}
+
+pub fn gen_fail_g2_mul_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_fail_mul_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2Mul.input_length::<S>(),
+		S::g2_size(),
+		S::encode_g2,
+		S::rand_g2_point_not_on_curve,
+	);
+	vectors.extend(gen_fail_subgroup_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2Mul,
+		1,
+		S::encode_g2,
+		S::rand_g2_point_not_on_correct_subgroup,
+		InputError::G2PointNotInSubgroup,
+	));
+	vectors
+}
+
+pub fn gen_fail_g2_multiexp_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_fail_multiexp_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2MultiExp.input_length::<S>(),
+		S::g2_size(),
+		S::encode_g2,
+		S::rand_g2_point_not_on_curve,
+	);
+	vectors.extend(gen_fail_subgroup_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2MultiExp,
+		3,
+		S::encode_g2,
+		S::rand_g2_point_not_on_correct_subgroup,
+		InputError::G2PointNotInSubgroup,
+	));
+	let pair = |rng: &mut TestRng| {
+		let mut pair = S::encode_g2(G2::<S>::rand(rng));
+		pair.extend(encode_fr::<S>(Fr::<S>::rand(rng)));
+		pair
+	};
+	let slot = PairSlot {
+		suffix: "",
+		offset: 0,
+		encode: S::encode_g2,
+		not_on_curve: S::rand_g2_point_not_on_curve,
+		not_in_subgroup: subgroup_check::<S, S::G2Config>(
+			Operation::G2MultiExp,
+			S::rand_g2_point_not_on_correct_subgroup,
+			InputError::G2PointNotInSubgroup,
+		),
+	};
+	vectors.extend(gen_pair_index_vectors::<S, S::G2Config>(rng, pair, slot));
+	vectors
+}
+
+pub fn gen_fail_pairing<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	let mut rng = seeded_rng(seed);
+	let pair_size = S::g1_size() + S::g2_size();
+	let mut vectors: Vec<VectorFail> =
+		gen_fail_vectors::<S>(Operation::Pairing.input_length::<S>(), 3 * pair_size);
+
+	// large modulus
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a1 = G1::<S>::rand(&mut rng);
+		let a2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(a1));
+		input_bytes.extend(S::encode_g2(a2));
+
+		let b1 = G1::<S>::rand(&mut rng);
+		let b2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(b1));
+		input_bytes.extend(S::encode_g2(b2));
+
+		// c1x
+		input_bytes.extend(modulus_plus::<S>(1));
+		// c1y, c2
+		input_bytes.extend(vec![0u8; pair_size - S::WORD_SIZE]);
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::InvalidFieldElement).to_string(),
+			name: String::from("large_field_element"),
+		};
+		vectors.push(vector);
+	}
+
+	// not on curve g1
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a1 = G1::<S>::rand(&mut rng);
+		let a2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(a1));
+		input_bytes.extend(S::encode_g2(a2));
+
+		let b1 = G1::<S>::rand(&mut rng);
+		let b2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(b1));
+		input_bytes.extend(S::encode_g2(b2));
+
+		let c1 = S::rand_g1_point_not_on_curve(&mut rng);
+		let c2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(c1));
+		input_bytes.extend(S::encode_g2(c2));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::PointNotOnCurve).to_string(),
+			name: String::from("point_not_on_curve_g1"),
+		};
+		vectors.push(vector);
+	}
+
+	// not on curve g2
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a1 = G1::<S>::rand(&mut rng);
+		let a2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(a1));
+		input_bytes.extend(S::encode_g2(a2));
+
+		let b1 = G1::<S>::rand(&mut rng);
+		let b2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(b1));
+		input_bytes.extend(S::encode_g2(b2));
+
+		let c1 = G1::<S>::rand(&mut rng);
+		let c2 = S::rand_g2_point_not_on_curve(&mut rng);
+		input_bytes.extend(S::encode_g1(c1));
+		input_bytes.extend(S::encode_g2(c2));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::PointNotOnCurve).to_string(),
+			name: String::from("point_not_on_curve_g2"),
+		};
+		vectors.push(vector);
+	}
+
+	// incorrect subgroup g1, unless every point of the curve is in G1
+	let checks_subgroup = Operation::Pairing.checks_subgroup::<S>();
+	if checks_subgroup && <S::G1Config as CurveConfig>::COFACTOR != [1] {
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a1 = G1::<S>::rand(&mut rng);
+		let a2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(a1));
+		input_bytes.extend(S::encode_g2(a2));
+
+		let b1 = G1::<S>::rand(&mut rng);
+		let b2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(b1));
+		input_bytes.extend(S::encode_g2(b2));
+
+		let c1 = S::rand_g1_point_not_on_correct_subgroup(&mut rng);
+		let c2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(c1));
+		input_bytes.extend(S::encode_g2(c2));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::G1PointNotInSubgroup).to_string(),
+			name: String::from("incorrect_subgroup_g1"),
+		};
+		vectors.push(vector);
+	}
+
+	// incorrect subgroup g2
+	if checks_subgroup {
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a1 = G1::<S>::rand(&mut rng);
+		let a2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(a1));
+		input_bytes.extend(S::encode_g2(a2));
+
+		let b1 = G1::<S>::rand(&mut rng);
+		let b2 = G2::<S>::rand(&mut rng);
+		input_bytes.extend(S::encode_g1(b1));
+		input_bytes.extend(S::encode_g2(b2));
+
+		let c1 = G1::<S>::rand(&mut rng);
+		let c2 = S::rand_g2_point_not_on_correct_subgroup(&mut rng);
+		input_bytes.extend(S::encode_g1(c1));
+		input_bytes.extend(S::encode_g2(c2));
+
+		let input: String = hex::encode(input_bytes);
+		let vector = VectorFail {
+			input,
+			expected_error: S::error_message(InputError::G2PointNotInSubgroup).to_string(),
+			name: String::from("incorrect_subgroup_g2"),
+		};
+		vectors.push(vector);
+	}
+
+	// non-canonical coordinates and padding of the points of either pair
+	{
+		let mut input_bytes: Vec<u8> = vec![];
+		let mut words = vec![];
+		for i in 0..2 {
+			words.extend(point_words::<S, S::G1Config>(&format!("pair{i}_g1"), input_bytes.len()));
+			input_bytes.extend(S::encode_g1(G1::<S>::rand(&mut rng)));
+			words.extend(point_words::<S, S::G2Config>(&format!("pair{i}_g2"), input_bytes.len()));
+			input_bytes.extend(S::encode_g2(G2::<S>::rand(&mut rng)));
+		}
+		vectors.extend(gen_non_canonical_vectors::<S>(&input_bytes, &words));
+		vectors.extend(gen_padding_vectors::<S>(&input_bytes, &words));
+	}
+
+	// each failure class at the first, middle and last pair, in either group
+	let pair = |rng: &mut TestRng| {
+		let mut pair = S::encode_g1(G1::<S>::rand(rng));
+		pair.extend(S::encode_g2(G2::<S>::rand(rng)));
+		pair
+	};
+	let g1 = PairSlot {
+		suffix: "_g1",
+		offset: 0,
+		encode: S::encode_g1,
+		not_on_curve: S::rand_g1_point_not_on_curve,
+		not_in_subgroup: subgroup_check::<S, S::G1Config>(
+			Operation::Pairing,
+			S::rand_g1_point_not_on_correct_subgroup,
+			InputError::G1PointNotInSubgroup,
+		),
+	};
+	vectors.extend(gen_pair_index_vectors::<S, S::G1Config>(&mut rng, pair, g1));
+	let g2 = PairSlot {
+		suffix: "_g2",
+		offset: S::g1_size(),
+		encode: S::encode_g2,
+		not_on_curve: S::rand_g2_point_not_on_curve,
+		not_in_subgroup: subgroup_check::<S, S::G2Config>(
+			Operation::Pairing,
+			S::rand_g2_point_not_on_correct_subgroup,
+			InputError::G2PointNotInSubgroup,
+		),
+	};
+	vectors.extend(gen_pair_index_vectors::<S, S::G2Config>(&mut rng, pair, g2));
+
+	vectors
+}
+
+pub fn gen_fail_g1_map_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	gen_fail_map_vectors::<S, <S::G1Config as CurveConfig>::BaseField>(
+		&mut seeded_rng(seed),
+		Operation::MapG1.input_length::<S>(),
+	)
+}
+
+pub fn gen_fail_g2_map_vectors<S: Suite>(seed: u64) -> Vec<VectorFail> {
+	gen_fail_map_vectors::<S, <S::G2Config as CurveConfig>::BaseField>(
+		&mut seeded_rng(seed),
+		Operation::MapG2.input_length::<S>(),
+	)
+}
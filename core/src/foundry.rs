@@ -0,0 +1,47 @@
+use crate::call::PrecompileCall;
+
+/// Name of the Foundry test contract of the vector file `test`, e.g. `FailBn256AddTest` for
+/// `fail-bn256Add`.
+pub fn foundry_contract_name(test: &str) -> String {
+	let mut name: String = test
+		.split(|c: char| !c.is_ascii_alphanumeric())
+		.filter(|part| !part.is_empty())
+		.map(|part| part[..1].to_ascii_uppercase() + &part[1..])
+		.collect();
+	name.push_str("Test");
+	name
+}
+
+/// A Foundry `.t.sol` contract named after the vector file `test` with a test function per call.
+/// Each function `staticcall`s the precompile at `address` with the gas of the call and asserts
+/// the output, or that the call fails.
+pub fn foundry_test(address: u64, test: &str, calls: &[PrecompileCall]) -> String {
+	let mut sol = format!("// SPDX-License-Identifier: MIT\n// {test} vectors of eip-test-gen.\n");
+	sol.push_str("pragma solidity ^0.8.13;\n\nimport {Test} from \"forge-std/Test.sol\";\n\n");
+	sol.push_str(&format!("contract {} is Test {{\n", foundry_contract_name(test)));
+	sol.push_str(&format!("\taddress constant PRECOMPILE = address({address:#04x});\n"));
+	for call in calls {
+		let (name, gas, input) = (identifier(&call.name), call.gas, &call.input);
+		sol.push_str(&format!("\n\tfunction test_{name}() public view {{\n"));
+		let staticcall = format!("PRECOMPILE.staticcall{{gas: {gas}}}(hex\"{input}\")");
+		match &call.output {
+			Some(output) => {
+				sol.push_str(&format!("\t\t(bool ok, bytes memory output) = {staticcall};\n"));
+				sol.push_str("\t\tassertTrue(ok);\n");
+				sol.push_str(&format!("\t\tassertEq(output, hex\"{output}\");\n"));
+			},
+			None => {
+				sol.push_str(&format!("\t\t(bool ok,) = {staticcall};\n"));
+				sol.push_str("\t\tassertFalse(ok);\n");
+			},
+		}
+		sol.push_str("\t}\n");
+	}
+	sol.push_str("}\n");
+	sol
+}
+
+/// `name` with every character Solidity does not allow in an identifier replaced by `_`.
+fn identifier(name: &str) -> String {
+	name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
@@ -0,0 +1,74 @@
+/// Gas prices of the precompiles of an EIP.
+#[derive(Clone, Copy, Debug)]
+pub struct GasSchedule {
+	pub g1_add: u64,
+	pub g1_mul: u64,
+	pub g2_add: u64,
+	pub g2_mul: u64,
+	pub pairing_base: u64,
+	pub pairing_per_pair: u64,
+	pub map_g1: u64,
+	pub map_g2: u64,
+	/// G1 multiexp discount of `k` pairs at index `k - 1`, in thousandths. The last entry applies
+	/// to every larger `k`.
+	pub g1_multiexp_discount: &'static [u64],
+	/// G2 multiexp discount, indexed like `g1_multiexp_discount`.
+	pub g2_multiexp_discount: &'static [u64],
+}
+
+impl GasSchedule {
+	/// `k * g1_mul * discount / 1000`, zero for no pairs.
+	pub fn g1_multiexp(&self, k: usize) -> u64 {
+		multiexp(self.g1_mul, self.g1_multiexp_discount, k)
+	}
+
+	/// `k * g2_mul * discount / 1000`, zero for no pairs.
+	pub fn g2_multiexp(&self, k: usize) -> u64 {
+		multiexp(self.g2_mul, self.g2_multiexp_discount, k)
+	}
+
+	/// `base + per_pair * k`.
+	pub fn pairing(&self, k: usize) -> u64 {
+		self.pairing_base + self.pairing_per_pair * k as u64
+	}
+}
+
+fn multiexp(mul_cost: u64, discounts: &[u64], k: usize) -> u64 {
+	if k == 0 {
+		return 0;
+	}
+	k as u64 * mul_cost * discounts[k.min(discounts.len()) - 1] / 1000
+}
+
+/// Multiexp discounts of the EIP-2537 draft, kept by EIP-2539 for both groups.
+pub const BLS12_MULTIEXP_DISCOUNT: [u64; 128] = [
+	1200, 888, 764, 641, 594, 547, 500, 453, 438, 423, 408, 394, 379, 364, 349, 334, 330, 326, 322,
+	318, 314, 310, 306, 302, 298, 294, 289, 285, 281, 277, 273, 269, 268, 266, 265, 263, 262, 260,
+	259, 257, 256, 254, 253, 251, 250, 248, 247, 245, 244, 242, 241, 239, 238, 236, 235, 233, 232,
+	231, 229, 228, 226, 225, 223, 222, 221, 220, 219, 219, 218, 217, 216, 216, 215, 214, 213, 213,
+	212, 211, 211, 210, 209, 208, 208, 207, 206, 205, 205, 204, 203, 202, 202, 201, 200, 199, 199,
+	198, 197, 196, 196, 195, 194, 193, 193, 192, 191, 191, 190, 189, 188, 188, 187, 186, 185, 185,
+	184, 183, 182, 182, 181, 180, 179, 179, 178, 177, 176, 176, 175, 174,
+];
+
+/// G1 multiexp discounts of the final EIP-2537.
+pub const BLS12_381_G1_MULTIEXP_DISCOUNT: [u64; 128] = [
+	1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665,
+	661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615,
+	613, 611, 609, 608, 606, 604, 603, 601, 599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585,
+	584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563,
+	562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545,
+	544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529,
+	528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519,
+];
+
+/// G2 multiexp discounts of the final EIP-2537.
+pub const BLS12_381_G2_MULTIEXP_DISCOUNT: [u64; 128] = [
+	1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704, 699,
+	693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632, 629,
+	627, 624, 622, 620, 618, 615, 613, 611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593, 592,
+	590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568, 567,
+	566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552, 551, 550, 549, 548,
+	547, 546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535, 534, 533,
+	532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524,
+];
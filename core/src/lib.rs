@@ -0,0 +1,38 @@
+mod call;
+mod check;
+mod config;
+mod decode;
+mod encode;
+mod error;
+mod exec;
+mod fail;
+mod foundry;
+mod gas;
+mod operation;
+mod rng;
+mod state_test;
+mod success;
+mod suite;
+mod vector;
+
+pub use call::*;
+pub use check::*;
+pub use config::*;
+pub use decode::*;
+pub use encode::*;
+pub use error::*;
+pub use exec::*;
+pub use fail::*;
+pub use foundry::*;
+pub use gas::*;
+pub use operation::*;
+pub use rng::*;
+pub use state_test::*;
+pub use success::*;
+pub use suite::*;
+pub use vector::*;
+
+pub const NUM_TESTS: usize = 100;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,230 @@
+use crate::{
+	config::Config,
+	error::InputError,
+	exec::*,
+	fail::*,
+	success::*,
+	suite::Suite,
+	vector::{VectorFail, VectorSuccess},
+};
+use ark_ec::CurveConfig;
+use ark_ff::Field;
+use std::{fmt, str::FromStr};
+
+/// A precompile of a [`Suite`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+	G1Add,
+	G1Mul,
+	G1MultiExp,
+	G2Add,
+	G2Mul,
+	G2MultiExp,
+	Pairing,
+	MapG1,
+	MapG2,
+}
+
+impl Operation {
+	pub const ALL: [Operation; 9] = [
+		Operation::G1Add,
+		Operation::G1Mul,
+		Operation::G1MultiExp,
+		Operation::G2Add,
+		Operation::G2Mul,
+		Operation::G2MultiExp,
+		Operation::Pairing,
+		Operation::MapG1,
+		Operation::MapG2,
+	];
+	/// Operations every EIP of the family specifies, i.e. all but the maps.
+	pub const ARITHMETIC: [Operation; 7] = [
+		Operation::G1Add,
+		Operation::G1Mul,
+		Operation::G1MultiExp,
+		Operation::G2Add,
+		Operation::G2Mul,
+		Operation::G2MultiExp,
+		Operation::Pairing,
+	];
+
+	/// Name of the operation as used in vector file names, e.g. `G1MultiExp`.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Operation::G1Add => "G1Add",
+			Operation::G1Mul => "G1Mul",
+			Operation::G1MultiExp => "G1MultiExp",
+			Operation::G2Add => "G2Add",
+			Operation::G2Mul => "G2Mul",
+			Operation::G2MultiExp => "G2MultiExp",
+			Operation::Pairing => "Pairing",
+			Operation::MapG1 => "MapG1",
+			Operation::MapG2 => "MapG2",
+		}
+	}
+
+	/// Input lengths the precompile of `S` accepts.
+	pub fn input_length<S: Suite>(&self) -> InputLength {
+		let fixed =
+			|len| if S::PADS_INPUT { InputLength::Padded(len) } else { InputLength::Exact(len) };
+		match self {
+			Operation::G1Add => fixed(2 * S::g1_size()),
+			Operation::G1Mul => fixed(S::g1_size() + S::SCALAR_SIZE),
+			Operation::G1MultiExp =>
+				InputLength::Pairs { pair_size: S::g1_size() + S::SCALAR_SIZE, empty: false },
+			Operation::G2Add => fixed(2 * S::g2_size()),
+			Operation::G2Mul => fixed(S::g2_size() + S::SCALAR_SIZE),
+			Operation::G2MultiExp =>
+				InputLength::Pairs { pair_size: S::g2_size() + S::SCALAR_SIZE, empty: false },
+			// EIP-197 pairs an empty input to `1`, the later EIPs reject it
+			Operation::Pairing =>
+				InputLength::Pairs { pair_size: S::g1_size() + S::g2_size(), empty: S::PADS_INPUT },
+			Operation::MapG1 => InputLength::Exact(S::WORD_SIZE * field_degree::<S::G1Config>()),
+			Operation::MapG2 => InputLength::Exact(S::WORD_SIZE * field_degree::<S::G2Config>()),
+		}
+	}
+
+	/// Whether the precompile of `S` rejects points outside of G1 and G2.
+	pub fn checks_subgroup<S: Suite>(&self) -> bool {
+		S::SUBGROUP_CHECKS.contains(self)
+	}
+
+	/// Gas the precompile of `S` charges for an input of `input_len` bytes.
+	pub fn gas<S: Suite>(&self, input_len: usize) -> u64 {
+		let gas = S::GAS;
+		let pairs = match self.input_length::<S>() {
+			InputLength::Pairs { pair_size, .. } => input_len / pair_size,
+			_ => 1,
+		};
+		match self {
+			Operation::G1Add => gas.g1_add,
+			Operation::G1Mul => gas.g1_mul,
+			Operation::G1MultiExp => gas.g1_multiexp(pairs),
+			Operation::G2Add => gas.g2_add,
+			Operation::G2Mul => gas.g2_mul,
+			Operation::G2MultiExp => gas.g2_multiexp(pairs),
+			Operation::Pairing => gas.pairing(pairs),
+			Operation::MapG1 => gas.map_g1,
+			Operation::MapG2 => gas.map_g2,
+		}
+	}
+
+	pub fn gen_vectors<S: Suite>(&self, seed: u64, config: &Config) -> Vec<VectorSuccess> {
+		let mut vectors = match self {
+			Operation::G1Add => gen_g1_add_vectors::<S>(seed, config.count),
+			Operation::G1Mul => gen_g1_mul_vectors::<S>(seed, config.count),
+			Operation::G1MultiExp => gen_g1_multiexp_vectors::<S>(seed, &config.sizes.to_vec()),
+			Operation::G2Add => gen_g2_add_vectors::<S>(seed, config.count),
+			Operation::G2Mul => gen_g2_mul_vectors::<S>(seed, config.count),
+			Operation::G2MultiExp => gen_g2_multiexp_vectors::<S>(seed, &config.sizes.to_vec()),
+			Operation::Pairing => gen_pairing_vectors::<S>(seed, &config.sizes.to_vec()),
+			Operation::MapG1 => gen_g1_map_vectors::<S>(seed, config.count),
+			Operation::MapG2 => gen_g2_map_vectors::<S>(seed, config.count),
+		};
+		for vector in &mut vectors {
+			vector.gas = self.gas::<S>(vector.input.len() / 2);
+		}
+		vectors
+	}
+
+	pub fn gen_fail_vectors<S: Suite>(&self, seed: u64) -> Vec<VectorFail> {
+		match self {
+			Operation::G1Add => gen_fail_g1_add_vectors::<S>(seed),
+			Operation::G1Mul => gen_fail_g1_mul_vectors::<S>(seed),
+			Operation::G1MultiExp => gen_fail_g1_multiexp_vectors::<S>(seed),
+			Operation::G2Add => gen_fail_g2_add_vectors::<S>(seed),
+			Operation::G2Mul => gen_fail_g2_mul_vectors::<S>(seed),
+			Operation::G2MultiExp => gen_fail_g2_multiexp_vectors::<S>(seed),
+			Operation::Pairing => gen_fail_pairing::<S>(seed),
+			Operation::MapG1 => gen_fail_g1_map_vectors::<S>(seed),
+			Operation::MapG2 => gen_fail_g2_map_vectors::<S>(seed),
+		}
+	}
+
+	/// Runs the precompile of `S` on `input`, returning its output or the error of the matching
+	/// [`VectorFail`].
+	pub fn execute<S: Suite>(&self, input: &[u8]) -> Result<Vec<u8>, InputError> {
+		let input = &self.input_length::<S>().read(input)?;
+		let (decode_g1, decode_g2): (Decode<S::G1Config>, Decode<S::G2Config>) =
+			match self.checks_subgroup::<S>() {
+				true => (S::decode_g1_in_subgroup, S::decode_g2_in_subgroup),
+				false => (S::decode_g1, S::decode_g2),
+			};
+		match self {
+			Operation::G1Add =>
+				execute_add::<S::G1Config>(input, S::g1_size(), decode_g1, S::encode_g1),
+			Operation::G1Mul =>
+				execute_mul::<S::G1Config>(input, S::g1_size(), decode_g1, S::encode_g1),
+			Operation::G1MultiExp =>
+				execute_multiexp::<S, S::G1Config>(input, S::g1_size(), decode_g1, S::encode_g1),
+			Operation::G2Add =>
+				execute_add::<S::G2Config>(input, S::g2_size(), decode_g2, S::encode_g2),
+			Operation::G2Mul =>
+				execute_mul::<S::G2Config>(input, S::g2_size(), decode_g2, S::encode_g2),
+			Operation::G2MultiExp =>
+				execute_multiexp::<S, S::G2Config>(input, S::g2_size(), decode_g2, S::encode_g2),
+			Operation::Pairing => execute_pairing::<S>(input, decode_g1, decode_g2),
+			Operation::MapG1 => execute_map::<S, S::G1Config>(input, S::map_to_g1, S::encode_g1),
+			Operation::MapG2 => execute_map::<S, S::G2Config>(input, S::map_to_g2, S::encode_g2),
+		}
+	}
+}
+
+/// Input lengths of a precompile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputLength {
+	/// Exactly `len` bytes.
+	Exact(usize),
+	/// Any length, right padded with zeros or cut to `len` bytes.
+	Padded(usize),
+	/// A multiple of `pair_size` bytes, zero pairs only if `empty`.
+	Pairs { pair_size: usize, empty: bool },
+}
+
+impl InputLength {
+	pub fn accepts(&self, len: usize) -> bool {
+		match *self {
+			InputLength::Exact(expected) => len == expected,
+			InputLength::Padded(_) => true,
+			InputLength::Pairs { pair_size, empty } =>
+				len.is_multiple_of(pair_size) && (len > 0 || empty),
+		}
+	}
+
+	/// `input` as the precompile reads it, i.e. padded or cut to a fixed length.
+	pub fn read(&self, input: &[u8]) -> Result<Vec<u8>, InputError> {
+		if !self.accepts(input.len()) {
+			return Err(InputError::InvalidLength);
+		}
+		let mut input = input.to_vec();
+		if let InputLength::Padded(len) = *self {
+			input.resize(len, 0);
+		}
+		Ok(input)
+	}
+}
+
+fn field_degree<P: CurveConfig>() -> usize {
+	P::BaseField::extension_degree() as usize
+}
+
+impl fmt::Display for Operation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl FromStr for Operation {
+	type Err = String;
+
+	/// Parses an operation name case-insensitively, ignoring `-` and `_`, so `G1MultiExp`,
+	/// `g1multiexp` and `g1-multiexp` are all accepted.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let normalized: String =
+			s.chars().filter(|c| *c != '-' && *c != '_').collect::<String>().to_lowercase();
+		Operation::ALL
+			.into_iter()
+			.find(|op| op.name().to_lowercase() == normalized)
+			.ok_or_else(|| format!("unknown operation `{s}`"))
+	}
+}
@@ -0,0 +1,11 @@
+use ark_std::rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// RNG every generator draws from. ChaCha20 keeps a corpus reproducible across `rand` releases.
+pub type TestRng = ChaCha20Rng;
+
+pub const DEFAULT_SEED: u64 = 0;
+
+pub fn seeded_rng(seed: u64) -> TestRng {
+	TestRng::seed_from_u64(seed)
+}
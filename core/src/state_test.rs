@@ -0,0 +1,128 @@
+use crate::call::PrecompileCall;
+use serde_json::{json, Map, Value};
+
+/// Contract calling the precompile in the state tests.
+const CALLER: &str = "0x0000000000000000000000000000000000ca11e0";
+/// Sender of the transactions, the usual account of the ethereum/tests fillers.
+const SENDER: &str = "0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b";
+const SENDER_KEY: &str = "0x45a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8";
+/// Gas of an `SSTORE` to a fresh slot, including the cold access.
+const SSTORE_GAS: u64 = 22_100;
+
+/// A GeneralStateTests filler named `test` making one transaction per call to the precompile at
+/// `address`, e.g. for `network` `>=Prague`.
+///
+/// The transaction data is the gas to forward as a 32 byte word followed by the precompile input.
+/// The caller contract stores whether the call succeeds with one gas less in slot 0, whether it
+/// succeeds with the gas in slot 1, the output size in slot 2 and the output words from slot 3
+/// on.
+pub fn state_test_filler(
+	address: u64,
+	test: &str,
+	network: &str,
+	calls: &[PrecompileCall],
+) -> Value {
+	let gas_limit = calls.iter().map(tx_gas).max().unwrap_or_default().to_string();
+	let data: Vec<_> = calls
+		.iter()
+		.map(|call| format!(":label {} :raw 0x{:064x}{}", call.name, call.gas, call.input))
+		.collect();
+	let expect: Vec<_> = calls
+		.iter()
+		.map(|call| {
+			json!({
+				"indexes": { "data": format!(":label {}", call.name), "gas": -1, "value": -1 },
+				"network": [network],
+				"result": { CALLER: { "storage": storage(call) } },
+			})
+		})
+		.collect();
+	json!({
+		test: {
+			"_info": { "comment": format!("{test} vectors of eip-test-gen") },
+			"env": {
+				"currentBaseFee": "10",
+				"currentCoinbase": "0x2adc25665018aa1fe0e6bc666dac8fc2697ff9ba",
+				"currentDifficulty": "0x020000",
+				"currentGasLimit": gas_limit,
+				"currentNumber": "1",
+				"currentRandom": format!("0x{:064x}", 0x020000),
+				"currentTimestamp": "1000",
+			},
+			"pre": {
+				CALLER: {
+					"balance": "0",
+					"code": caller_code(address),
+					"nonce": "1",
+					"storage": {},
+				},
+				SENDER: {
+					"balance": "0xffffffffffffffffffffffffffff",
+					"code": "",
+					"nonce": "0",
+					"storage": {},
+				},
+			},
+			"transaction": {
+				"data": data,
+				"gasLimit": [gas_limit],
+				"gasPrice": "10",
+				"nonce": "0",
+				"secretKey": SENDER_KEY,
+				"to": CALLER,
+				"value": ["0"],
+			},
+			"expect": expect,
+		}
+	})
+}
+
+fn caller_code(address: u64) -> String {
+	format!(
+		":yul {{
+	let gas_limit := calldataload(0)
+	let size := sub(calldatasize(), 32)
+	calldatacopy(0, 32, size)
+	sstore(0, call(sub(gas_limit, 1), {address:#04x}, 0, 0, size, 0, 0))
+	sstore(1, call(gas_limit, {address:#04x}, 0, 0, size, 0, 0))
+	sstore(2, returndatasize())
+	returndatacopy(0, 0, returndatasize())
+	for {{ let i := 0 }} lt(i, returndatasize()) {{ i := add(i, 32) }} {{
+		sstore(add(3, div(i, 32)), mload(i))
+	}}
+}}"
+	)
+}
+
+/// Post-state storage of the caller contract after the transaction of `call`. Precompile
+/// outputs are whole words.
+fn storage(call: &PrecompileCall) -> Map<String, Value> {
+	let output = call.output.as_deref().map(|output| hex::decode(output).expect("hex output"));
+	let mut storage = Map::new();
+	storage.insert(slot(0), word(&[]).into());
+	storage.insert(slot(1), word(&[u8::from(output.is_some())]).into());
+	let output = output.unwrap_or_default();
+	storage.insert(slot(2), word(&(output.len() as u64).to_be_bytes()).into());
+	for (i, chunk) in output.chunks(32).enumerate() {
+		storage.insert(slot(3 + i), word(chunk).into());
+	}
+	storage
+}
+
+fn slot(i: usize) -> String {
+	word(&(i as u64).to_be_bytes())
+}
+
+/// `bytes` as a hex storage key or value without leading zero bytes, `0x00` for zero.
+fn word(bytes: &[u8]) -> String {
+	let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+	let bytes = if leading_zeros == bytes.len() { &[0][..] } else { &bytes[leading_zeros..] };
+	format!("0x{}", hex::encode(bytes))
+}
+
+/// Gas limit of the transaction of `call`: two calls, the stores and the calldata floor.
+fn tx_gas(call: &PrecompileCall) -> u64 {
+	let data_len = 32 + call.input.len() as u64 / 2;
+	let output_words = call.output.as_ref().map_or(0, |output| output.len() as u64 / 64);
+	21_000 + 40 * data_len + 3 * call.gas + SSTORE_GAS * (3 + output_words) + 100_000
+}
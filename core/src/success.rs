@@ -0,0 +1,623 @@
+use crate::{
+	decode::decode_scalar,
+	encode::{encode_field, encode_fr},
+	operation::Operation,
+	rng::{seeded_rng, TestRng},
+	suite::{Fr, Suite, G1, G2},
+	vector::VectorSuccess,
+};
+use ark_ec::{
+	short_weierstrass::{Projective, SWCurveConfig},
+	Group,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{
+	ops::{Mul, Neg},
+	UniformRand, Zero,
+};
+use std::iter;
+
+fn gen_add_vectors<S: Suite, P: SWCurveConfig>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	count: usize,
+) -> Vec<VectorSuccess> {
+	let mut vectors: Vec<VectorSuccess> = vec![];
+	for i in 0..count {
+		let mut input_bytes: Vec<u8> = vec![];
+		let a = Projective::<P>::rand(rng);
+		let b = Projective::<P>::rand(rng);
+		input_bytes.extend(encode(a));
+		input_bytes.extend(encode(b));
+		let input: String = hex::encode(input_bytes);
+
+		let r = a + b;
+		let result: String = hex::encode(encode(r));
+		let vector = VectorSuccess {
+			input,
+			expected: result,
+			name: format!("{}_{}", name, i + 1),
+			gas: 0,
+			no_benchmark: false,
+		};
+		vectors.push(vector);
+	}
+	if S::PADS_INPUT {
+		vectors.extend(gen_padded_add_vectors::<S, P>(rng, name, encode));
+	}
+	vectors.extend(gen_edge_add_vectors(rng, name, encode));
+	vectors
+}
+
+/// Additions taking the identity, doubling and inverse branches of client formulas. Only `-a`
+/// shares the x coordinate of `a`, so the equal x case adds the generator to its negation.
+fn gen_edge_add_vectors<P: SWCurveConfig>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+) -> Vec<VectorSuccess> {
+	let a = Projective::<P>::rand(rng);
+	let o = Projective::<P>::zero();
+	let g = Projective::<P>::generator();
+	[
+		(a, o, "p_plus_o"),
+		(o, a, "o_plus_p"),
+		(o, o, "o_plus_o"),
+		(a, a, "doubling"),
+		(a, -a, "inverse"),
+		(g, -g, "equal_x"),
+	]
+	.into_iter()
+	.map(|(a, b, case)| {
+		let mut input_bytes = encode(a);
+		input_bytes.extend(encode(b));
+		VectorSuccess {
+			input: hex::encode(input_bytes),
+			expected: hex::encode(encode(a + b)),
+			name: format!("{name}_{case}"),
+			gas: 0,
+			no_benchmark: true,
+		}
+	})
+	.collect()
+}
+
+/// Add inputs that are only valid because they are padded or cut to the expected length.
+fn gen_padded_add_vectors<S: Suite, P: SWCurveConfig>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+) -> Vec<VectorSuccess> {
+	let a = Projective::<P>::rand(rng);
+	let b = Projective::<P>::rand(rng);
+	let mut surplus = encode(a);
+	surplus.extend(encode(b));
+	surplus.extend(vec![1u8; S::WORD_SIZE]);
+
+	// O + O, a + O with O cut off, a + b followed by a surplus word
+	[
+		(vec![], Projective::<P>::zero(), "empty"),
+		(encode(a), a, "truncated"),
+		(surplus, a + b, "surplus"),
+	]
+	.into_iter()
+	.map(|(input, r, case)| VectorSuccess {
+		input: hex::encode(input),
+		expected: hex::encode(encode(r)),
+		name: format!("{name}_{case}"),
+		gas: 0,
+		no_benchmark: true,
+	})
+	.collect()
+}
+
+fn gen_mul_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	count: usize,
+) -> Vec<VectorSuccess> {
+	let mut vectors: Vec<VectorSuccess> = vec![];
+	for i in 0..count {
+		let mut input_bytes: Vec<u8> = vec![];
+
+		let a = Projective::<P>::rand(rng);
+		let e = Fr::<S>::rand(rng);
+		input_bytes.extend(encode(a));
+		input_bytes.extend(encode_fr::<S>(e));
+		let input: String = hex::encode(input_bytes);
+
+		let r = a.mul(e);
+		let result: String = hex::encode(encode(r));
+		let vector = VectorSuccess {
+			input,
+			expected: result,
+			name: format!("{}_{}", name, i + 1),
+			gas: 0,
+			no_benchmark: false,
+		};
+		vectors.push(vector);
+	}
+	if S::PADS_INPUT {
+		vectors.extend(gen_padded_mul_vectors::<S, P>(rng, name, encode));
+	}
+	vectors.extend(gen_edge_mul_vectors::<S, P>(rng, name, encode));
+	vectors
+}
+
+/// Muls by the scalars `0`, `1`, `r - 1`, `r`, `r + 1` and `2^bits - 1`, and of the point at
+/// infinity. Scalars are not reduced, so outputs are computed from the raw integer.
+fn gen_edge_mul_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+) -> Vec<VectorSuccess> {
+	let a = Projective::<P>::rand(rng);
+	let e = Fr::<S>::rand(rng);
+	let scalar = |n: <Fr<S> as PrimeField>::BigInt| {
+		let rep = n.to_bytes_be();
+		let mut result = vec![0u8; S::SCALAR_SIZE];
+		result[S::SCALAR_SIZE - rep.len()..].copy_from_slice(&rep);
+		result
+	};
+	let r = Fr::<S>::MODULUS;
+	let (mut r_minus_one, mut r_plus_one) = (r, r);
+	r_minus_one.sub_with_borrow(&1u64.into());
+	r_plus_one.add_with_carry(&1u64.into());
+	[
+		(a, scalar(0u64.into()), "zero"),
+		(a, scalar(1u64.into()), "one"),
+		(a, scalar(r_minus_one), "order_minus_one"),
+		(a, scalar(r), "order"),
+		(a, scalar(r_plus_one), "order_plus_one"),
+		(a, vec![0xffu8; S::SCALAR_SIZE], "max"),
+		(Projective::<P>::zero(), encode_fr::<S>(e), "infinity"),
+	]
+	.into_iter()
+	.map(|(a, e, case)| {
+		let mut input_bytes = encode(a);
+		input_bytes.extend(&e);
+		VectorSuccess {
+			input: hex::encode(input_bytes),
+			expected: hex::encode(encode(a.mul_bigint(decode_scalar(&e)))),
+			name: format!("{name}_{case}"),
+			gas: 0,
+			no_benchmark: true,
+		}
+	})
+	.collect()
+}
+
+/// Mul inputs that are only valid because they are padded or cut to the expected length.
+fn gen_padded_mul_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+) -> Vec<VectorSuccess> {
+	let a = Projective::<P>::rand(rng);
+	let e = Fr::<S>::rand(rng);
+	// 256 ends with a zero byte, so cutting it off keeps the scalar
+	let mut truncated_scalar = encode(a);
+	truncated_scalar.extend(encode_fr::<S>(Fr::<S>::from(256u64)));
+	truncated_scalar.pop();
+	let mut surplus = encode(a);
+	surplus.extend(encode_fr::<S>(e));
+	surplus.extend(vec![1u8; S::WORD_SIZE]);
+
+	[
+		(vec![], Projective::<P>::zero(), "empty"),
+		(encode(a), Projective::<P>::zero(), "truncated"),
+		(truncated_scalar, a.mul(Fr::<S>::from(256u64)), "truncated_scalar"),
+		(surplus, a.mul(e), "surplus"),
+	]
+	.into_iter()
+	.map(|(input, r, case)| VectorSuccess {
+		input: hex::encode(input),
+		expected: hex::encode(encode(r)),
+		name: format!("{name}_{case}"),
+		gas: 0,
+		no_benchmark: true,
+	})
+	.collect()
+}
+
+fn gen_multiexp_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	seed: u64,
+	op: Operation,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_in_subgroup: fn(&mut TestRng) -> Projective<P>,
+	sizes: &[usize],
+) -> Vec<VectorSuccess> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors: Vec<VectorSuccess> = vec![];
+	for &pair_size in sizes {
+		let mut input_bytes: Vec<u8> = vec![];
+		let mut acc = Projective::<P>::zero();
+		for _ in 0..pair_size {
+			let a = Projective::<P>::rand(rng);
+			let e = Fr::<S>::rand(rng);
+			input_bytes.extend(encode(a));
+			input_bytes.extend(encode_fr::<S>(e));
+
+			acc += a.mul(e);
+		}
+		let input: String = hex::encode(input_bytes);
+
+		let result: String = hex::encode(encode(acc));
+		let vector = VectorSuccess {
+			input,
+			expected: result,
+			name: format!("{}_{}", name, pair_size + 1),
+			gas: 0,
+			no_benchmark: false,
+		};
+		vectors.push(vector);
+	}
+	vectors.extend(gen_edge_multiexp_vectors::<S, P>(rng, seed, name, encode));
+	vectors.extend(gen_outside_subgroup_vectors::<S, P>(rng, op, name, encode, not_in_subgroup));
+	vectors
+}
+
+/// Multiexps with points at infinity, zero scalars, a repeated base and pairs cancelling to
+/// infinity, and a single pair equal to the first mul vector of `seed`.
+fn gen_edge_multiexp_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	seed: u64,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+) -> Vec<VectorSuccess> {
+	let o = Projective::<P>::zero();
+	let zero = Fr::<S>::zero();
+	let (a, b, c) =
+		(Projective::<P>::rand(rng), Projective::<P>::rand(rng), Projective::<P>::rand(rng));
+	let (e1, e2, e3) = (Fr::<S>::rand(rng), Fr::<S>::rand(rng), Fr::<S>::rand(rng));
+	// the first mul vector draws its point and scalar first
+	let mul_rng = &mut seeded_rng(seed);
+	let mul_pair = (Projective::<P>::rand(mul_rng), Fr::<S>::rand(mul_rng));
+	[
+		(vec![(o, e1), (a, e2), (o, e3)], "infinity"),
+		(vec![(a, zero), (b, e2), (c, zero)], "zero_scalar"),
+		(vec![(a, e1), (a, e2), (a, e3)], "repeated_base"),
+		(vec![(a, e1), (b, e2), (-a, e1), (b, -e2)], "cancellation"),
+		(vec![mul_pair], "single_pair"),
+	]
+	.into_iter()
+	.map(|(pairs, case)| {
+		let mut input_bytes: Vec<u8> = vec![];
+		let mut acc = Projective::<P>::zero();
+		for (a, e) in pairs {
+			input_bytes.extend(encode(a));
+			input_bytes.extend(encode_fr::<S>(e));
+			acc += a.mul(e);
+		}
+		VectorSuccess {
+			input: hex::encode(input_bytes),
+			expected: hex::encode(encode(acc)),
+			name: format!("{name}_{case}"),
+			gas: 0,
+			no_benchmark: true,
+		}
+	})
+	.collect()
+}
+
+/// Points outside of the subgroup are valid input of operations that do not check the subgroup,
+/// if the curve has such points. Add takes two of them, mul one and multiexp two pairs.
+fn gen_outside_subgroup_vectors<S: Suite, P: SWCurveConfig<ScalarField = Fr<S>>>(
+	rng: &mut TestRng,
+	op: Operation,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	not_in_subgroup: fn(&mut TestRng) -> Projective<P>,
+) -> Vec<VectorSuccess> {
+	if op.checks_subgroup::<S>() || P::COFACTOR == [1] {
+		return vec![];
+	}
+	let mut input_bytes: Vec<u8> = vec![];
+	let mut r = Projective::<P>::zero();
+	match op {
+		Operation::G1Add | Operation::G2Add =>
+			for _ in 0..2 {
+				let a = not_in_subgroup(rng);
+				input_bytes.extend(encode(a));
+				r += a;
+			},
+		_ => {
+			let pairs = if op == Operation::G1Mul || op == Operation::G2Mul { 1 } else { 2 };
+			for _ in 0..pairs {
+				let a = not_in_subgroup(rng);
+				let e = encode_fr::<S>(Fr::<S>::rand(rng));
+				input_bytes.extend(encode(a));
+				input_bytes.extend(&e);
+				r += a.mul_bigint(decode_scalar(&e));
+			}
+		},
+	}
+	vec![VectorSuccess {
+		input: hex::encode(input_bytes),
+		expected: hex::encode(encode(r)),
+		name: format!("{name}_outside_subgroup"),
+		gas: 0,
+		no_benchmark: true,
+	}]
+}
+
+fn gen_map_vectors<S: Suite, P: SWCurveConfig>(
+	rng: &mut TestRng,
+	name: &str,
+	encode: fn(Projective<P>) -> Vec<u8>,
+	map: fn(P::BaseField) -> Option<Projective<P>>,
+	count: usize,
+) -> Vec<VectorSuccess> {
+	let mut vectors: Vec<VectorSuccess> = vec![];
+	for i in 0..count {
+		let fe = P::BaseField::rand(rng);
+		let input: String = hex::encode(encode_field(fe, S::WORD_SIZE));
+
+		let r = map(fe).expect("suite must implement the maps it lists");
+		let result: String = hex::encode(encode(r));
+		let vector = VectorSuccess {
+			input,
+			expected: result,
+			name: format!("{}_{}", name, i + 1),
+			gas: 0,
+			no_benchmark: false,
+		};
+		vectors.push(vector);
+	}
+	vectors
+}
+
+pub fn gen_g1_add_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_add_vectors::<S, S::G1Config>(rng, "g1_add", S::encode_g1, count);
+	vectors.extend(gen_outside_subgroup_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1Add,
+		"g1_add",
+		S::encode_g1,
+		S::rand_g1_point_not_on_correct_subgroup,
+	));
+	vectors
+}
+
+pub fn gen_g1_mul_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_mul_vectors::<S, S::G1Config>(rng, "g1_mul", S::encode_g1, count);
+	vectors.extend(gen_outside_subgroup_vectors::<S, S::G1Config>(
+		rng,
+		Operation::G1Mul,
+		"g1_mul",
+		S::encode_g1,
+		S::rand_g1_point_not_on_correct_subgroup,
+	));
+	vectors
+}
+
+pub fn gen_g1_multiexp_vectors<S: Suite>(seed: u64, sizes: &[usize]) -> Vec<VectorSuccess> {
+	gen_multiexp_vectors::<S, S::G1Config>(
+		seed,
+		Operation::G1MultiExp,
+		"g1_multiexp",
+		S::encode_g1,
+		S::rand_g1_point_not_on_correct_subgroup,
+		sizes,
+	)
+}
+
+pub fn gen_g2_add_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_add_vectors::<S, S::G2Config>(rng, "g2_add", S::encode_g2, count);
+	vectors.extend(gen_outside_subgroup_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2Add,
+		"g2_add",
+		S::encode_g2,
+		S::rand_g2_point_not_on_correct_subgroup,
+	));
+	vectors
+}
+
+pub fn gen_g2_mul_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	let rng = &mut seeded_rng(seed);
+	let mut vectors = gen_mul_vectors::<S, S::G2Config>(rng, "g2_mul", S::encode_g2, count);
+	vectors.extend(gen_outside_subgroup_vectors::<S, S::G2Config>(
+		rng,
+		Operation::G2Mul,
+		"g2_mul",
+		S::encode_g2,
+		S::rand_g2_point_not_on_correct_subgroup,
+	));
+	vectors
+}
+
+pub fn gen_g2_multiexp_vectors<S: Suite>(seed: u64, sizes: &[usize]) -> Vec<VectorSuccess> {
+	gen_multiexp_vectors::<S, S::G2Config>(
+		seed,
+		Operation::G2MultiExp,
+		"g2_multiexp",
+		S::encode_g2,
+		S::rand_g2_point_not_on_correct_subgroup,
+		sizes,
+	)
+}
+
+pub fn gen_g1_map_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	gen_map_vectors::<S, S::G1Config>(
+		&mut seeded_rng(seed),
+		"g1_map",
+		S::encode_g1,
+		S::map_to_g1,
+		count,
+	)
+}
+
+pub fn gen_g2_map_vectors<S: Suite>(seed: u64, count: usize) -> Vec<VectorSuccess> {
+	gen_map_vectors::<S, S::G2Config>(
+		&mut seeded_rng(seed),
+		"g2_map",
+		S::encode_g2,
+		S::map_to_g2,
+		count,
+	)
+}
+
+pub fn gen_pairing_vectors<S: Suite>(seed: u64, sizes: &[usize]) -> Vec<VectorSuccess> {
+	let mut rng = seeded_rng(seed);
+	let mut vectors: Vec<VectorSuccess> = vec![];
+	let mut positive_result_bytes: Vec<u8> = vec![0u8; 32];
+	positive_result_bytes[31] = 1u8;
+	let negative_result_bytes: Vec<u8> = vec![0u8; 32];
+	let g1_inf_encoded: Vec<u8> = vec![0u8; S::g1_size()];
+	let g2_inf_encoded: Vec<u8> = vec![0u8; S::g2_size()];
+
+	let g1 = G1::<S>::generator();
+	let g2 = G2::<S>::generator();
+
+	// expect true
+	{
+		// empty input, accepted by EIP-197
+		if Operation::Pairing.input_length::<S>().accepts(0) {
+			let vector = VectorSuccess {
+				input: String::new(),
+				expected: hex::encode(&positive_result_bytes),
+				name: String::from("g2_pairing_empty"),
+				gas: 0,
+				no_benchmark: true,
+			};
+			vectors.push(vector);
+		}
+
+		// a. single pair
+		{
+			let mut input_bytes: Vec<u8> = vec![];
+			input_bytes.extend(g1_inf_encoded);
+			input_bytes.extend(S::encode_g2(g2));
+
+			let input: String = hex::encode(input_bytes);
+			let vector = VectorSuccess {
+				input,
+				expected: hex::encode(&positive_result_bytes),
+				name: String::from("g2_pairing_g1_infinity"),
+				gas: 0,
+				no_benchmark: true,
+			};
+			vectors.push(vector);
+
+			let mut input_bytes: Vec<u8> = vec![];
+			input_bytes.extend(S::encode_g1(g1));
+			input_bytes.extend(g2_inf_encoded);
+
+			let input: String = hex::encode(input_bytes);
+			let vector = VectorSuccess {
+				input,
+				expected: hex::encode(&positive_result_bytes),
+				name: String::from("g2_pairing_g2_infinity"),
+				gas: 0,
+				no_benchmark: true,
+			};
+			vectors.push(vector);
+		}
+
+		// b. multiple pair
+		{
+			for &pair_size in sizes {
+				let mut acc = Fr::<S>::zero();
+				let mut input_bytes: Vec<u8> = vec![];
+				// n-1 pairs
+				for _ in 0..pair_size - 1 {
+					let e1 = Fr::<S>::rand(&mut rng);
+					let e2 = Fr::<S>::rand(&mut rng);
+					let a1 = g1.mul(e1);
+					let a2 = g2.mul(e2);
+					input_bytes.extend(S::encode_g1(a1));
+					input_bytes.extend(S::encode_g2(a2));
+					acc += e1 * e2;
+				}
+				// last pair
+				let a1 = g1.mul(acc.neg());
+				let a2 = g2;
+				input_bytes.extend(S::encode_g1(a1));
+				input_bytes.extend(S::encode_g2(a2));
+
+				let input: String = hex::encode(input_bytes);
+				let result: String = hex::encode(&positive_result_bytes);
+
+				let vector = VectorSuccess {
+					input,
+					expected: result,
+					name: format!("{}_{}", "g2_pairing", pair_size),
+					gas: 0,
+					no_benchmark: false,
+				};
+				vectors.push(vector);
+			}
+		}
+	}
+
+	// expect false, for a single pair and every configured pair count
+	{
+		let false_sizes = iter::once(1).chain(sizes.iter().copied().filter(|&k| k != 1));
+		for (i, pair_size) in false_sizes.enumerate() {
+			let mut input_bytes: Vec<u8> = vec![];
+			for _ in 0..pair_size {
+				let e1 = Fr::<S>::rand(&mut rng);
+				let e2 = Fr::<S>::rand(&mut rng);
+				let a1 = g1.mul(e1);
+				let a2 = g2.mul(e2);
+				input_bytes.extend(S::encode_g1(a1));
+				input_bytes.extend(S::encode_g2(a2));
+			}
+
+			let input: String = hex::encode(input_bytes);
+			let result: String = hex::encode(&negative_result_bytes);
+
+			let vector = VectorSuccess {
+				input,
+				expected: result,
+				name: format!("{}_{}", "g2_pairing_0", sizes.len() + i + 2),
+				gas: 0,
+				no_benchmark: false,
+			};
+			vectors.push(vector);
+		}
+	}
+
+	// expect true, bilinearity with infinities in between and scalars on either side
+	{
+		let a = Fr::<S>::rand(&mut rng);
+		let b = Fr::<S>::rand(&mut rng);
+		let p = g1.mul(Fr::<S>::rand(&mut rng));
+		let q = g2.mul(Fr::<S>::rand(&mut rng));
+		let o1 = G1::<S>::zero();
+		let o2 = G2::<S>::zero();
+		let cases = [
+			(vec![(p.mul(a), q.mul(b)), (-p.mul(a * b), q)], "bilinear"),
+			(vec![(p, q), (-p, q)], "inverse"),
+			(
+				vec![(p.mul(a), q.mul(b)), (o1, q), (p, o2), (o1, o2), (-p.mul(a * b), q)],
+				"infinity_middle",
+			),
+			(vec![(p, q.mul(a * b)), (-p.mul(a), q.mul(b))], "g2_scalar"),
+		];
+		for (pairs, case) in cases {
+			let mut input_bytes: Vec<u8> = vec![];
+			for (a1, a2) in pairs {
+				input_bytes.extend(S::encode_g1(a1));
+				input_bytes.extend(S::encode_g2(a2));
+			}
+			let vector = VectorSuccess {
+				input: hex::encode(input_bytes),
+				expected: hex::encode(&positive_result_bytes),
+				name: format!("g2_pairing_{case}"),
+				gas: 0,
+				no_benchmark: true,
+			};
+			vectors.push(vector);
+		}
+	}
+
+	vectors
+}
@@ -0,0 +1,172 @@
+use crate::{
+	decode::{check_subgroup, decode_point},
+	encode::encode_point,
+	error::InputError,
+	gas::GasSchedule,
+	operation::Operation,
+};
+use ark_ec::{
+	hashing::{
+		curve_maps::wb::{WBConfig, WBMap},
+		map_to_curve_hasher::MapToCurve,
+	},
+	pairing::Pairing,
+	short_weierstrass::{Affine, Projective, SWCurveConfig},
+	AffineRepr, CurveConfig,
+};
+use ark_ff::Field;
+use ark_std::{rand::Rng, UniformRand};
+
+pub type G1<S> = <<S as Suite>::Engine as Pairing>::G1;
+pub type G2<S> = <<S as Suite>::Engine as Pairing>::G2;
+pub type Fq<S> = <<<S as Suite>::G1Config as CurveConfig>::BaseField as Field>::BasePrimeField;
+pub type Fr<S> = <<S as Suite>::Engine as Pairing>::ScalarField;
+
+/// A family of curve precompiles sharing one input encoding, e.g. the BLS12-377 precompiles of
+/// EIP-2539.
+pub trait Suite {
+	/// Pairing engine the precompiles operate on.
+	type Engine: Pairing<G1 = Projective<Self::G1Config>, G2 = Projective<Self::G2Config>>;
+	type G1Config: SWCurveConfig<ScalarField = <Self::Engine as Pairing>::ScalarField>;
+	type G2Config: SWCurveConfig<ScalarField = <Self::Engine as Pairing>::ScalarField>;
+
+	/// File name prefix of the generated vectors, e.g. `bls12377`.
+	const PREFIX: &'static str;
+	/// Address of each precompile the exported tests call. Operations without one are not
+	/// exported.
+	const ADDRESSES: &'static [(Operation, u64)];
+	/// Byte size of a base field element.
+	const FE_SIZE: usize;
+	/// Byte size of an encoded base field element, including the top zero padding.
+	const WORD_SIZE: usize;
+	/// Byte size of an encoded scalar.
+	const SCALAR_SIZE: usize;
+	/// Gas prices of the precompiles.
+	const GAS: GasSchedule;
+	/// Whether add and mul right pad short input with zeros and ignore surplus bytes, and pairing
+	/// accepts an empty input, as EIP-196 and EIP-197 specify.
+	const PADS_INPUT: bool = false;
+	/// Operations the EIP specifies a precompile for.
+	const OPERATIONS: &'static [Operation] = &Operation::ARITHMETIC;
+	/// Operations rejecting points outside of G1 and G2.
+	const SUBGROUP_CHECKS: &'static [Operation];
+
+	/// Name of `op` in the vector file names. Defaults to [`Operation::name`], the names
+	/// go-ethereum gives the EIP-2537 test files.
+	fn file_op_name(op: Operation) -> &'static str {
+		op.name()
+	}
+
+	/// `expected_error` of the failure vectors rejected with `error`. Defaults to the wording of
+	/// the EIP-2539 reference implementation, [`InputError::as_str`].
+	fn error_message(error: InputError) -> &'static str {
+		error.as_str()
+	}
+
+	/// Address of the precompile of `op` in [`Suite::ADDRESSES`], `None` if it has none.
+	fn address(op: Operation) -> Option<u64> {
+		Self::ADDRESSES.iter().find(|(o, _)| *o == op).map(|&(_, address)| address)
+	}
+
+	fn g1_size() -> usize {
+		point_size::<Self::G1Config>(Self::WORD_SIZE)
+	}
+
+	fn g2_size() -> usize {
+		point_size::<Self::G2Config>(Self::WORD_SIZE)
+	}
+
+	fn encode_g1(g1: G1<Self>) -> Vec<u8> {
+		encode_point(g1, Self::WORD_SIZE)
+	}
+
+	fn encode_g2(g2: G2<Self>) -> Vec<u8> {
+		encode_point(g2, Self::WORD_SIZE)
+	}
+
+	/// Decodes exactly [`Suite::g1_size`] bytes written by [`Suite::encode_g1`], rejecting
+	/// invalid padding, non-canonical coordinates and points off the curve. The subgroup is not
+	/// checked.
+	fn decode_g1(bytes: &[u8]) -> Result<G1<Self>, InputError> {
+		decode_point(bytes, Self::WORD_SIZE, Self::FE_SIZE)
+	}
+
+	/// Decodes exactly [`Suite::g2_size`] bytes written by [`Suite::encode_g2`], rejecting
+	/// invalid padding, non-canonical coordinates and points off the curve. The subgroup is not
+	/// checked.
+	fn decode_g2(bytes: &[u8]) -> Result<G2<Self>, InputError> {
+		decode_point(bytes, Self::WORD_SIZE, Self::FE_SIZE)
+	}
+
+	/// [`Suite::decode_g1`] also rejecting points outside of G1.
+	fn decode_g1_in_subgroup(bytes: &[u8]) -> Result<G1<Self>, InputError> {
+		check_subgroup(Self::decode_g1(bytes)?, InputError::G1PointNotInSubgroup)
+	}
+
+	/// [`Suite::decode_g2`] also rejecting points outside of G2.
+	fn decode_g2_in_subgroup(bytes: &[u8]) -> Result<G2<Self>, InputError> {
+		check_subgroup(Self::decode_g2(bytes)?, InputError::G2PointNotInSubgroup)
+	}
+
+	/// Maps a field element to G1 like the map precompile, `None` if the EIP has none.
+	fn map_to_g1(_fe: <Self::G1Config as CurveConfig>::BaseField) -> Option<G1<Self>> {
+		None
+	}
+
+	/// Maps a field element to G2 like the map precompile, `None` if the EIP has none.
+	fn map_to_g2(_fe: <Self::G2Config as CurveConfig>::BaseField) -> Option<G2<Self>> {
+		None
+	}
+
+	fn rand_g1_point_not_on_curve<R: Rng + ?Sized>(rng: &mut R) -> G1<Self> {
+		rand_point_not_on_curve(rng)
+	}
+
+	fn rand_g2_point_not_on_curve<R: Rng + ?Sized>(rng: &mut R) -> G2<Self> {
+		rand_point_not_on_curve(rng)
+	}
+
+	fn rand_g1_point_not_on_correct_subgroup<R: Rng + ?Sized>(rng: &mut R) -> G1<Self> {
+		rand_point_not_on_correct_subgroup(rng)
+	}
+
+	fn rand_g2_point_not_on_correct_subgroup<R: Rng + ?Sized>(rng: &mut R) -> G2<Self> {
+		rand_point_not_on_correct_subgroup(rng)
+	}
+}
+
+/// Simplified SWU map onto an isogenous curve followed by the isogeny and cofactor clearing.
+pub fn map_to_curve<P: WBConfig>(fe: P::BaseField) -> Projective<P> {
+	let map = WBMap::<P>::new().expect("isogeny must map onto the curve");
+	map.map_to_curve(fe).expect("every field element must map").clear_cofactor().into()
+}
+
+fn point_size<P: SWCurveConfig>(word_size: usize) -> usize {
+	2 * word_size * P::BaseField::extension_degree() as usize
+}
+
+fn rand_point_not_on_curve<P: SWCurveConfig, R: Rng + ?Sized>(rng: &mut R) -> Projective<P> {
+	let x = P::BaseField::rand(rng);
+	let y = P::BaseField::rand(rng);
+	let p = Affine::<P>::new_unchecked(x, y);
+	assert!(!p.is_on_curve());
+	p.into()
+}
+
+fn rand_point_not_on_correct_subgroup<P: SWCurveConfig, R: Rng + ?Sized>(
+	rng: &mut R,
+) -> Projective<P> {
+	loop {
+		let x = P::BaseField::rand(rng);
+		let mut y = x * x;
+		y *= x;
+		y += P::mul_by_a(x);
+		y = P::add_b(y);
+		if let Some(y) = y.sqrt() {
+			let p = Affine::<P>::new_unchecked(x, y);
+			assert!(p.is_on_curve());
+			assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+			return p.into();
+		}
+	}
+}
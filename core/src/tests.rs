@@ -0,0 +1,63 @@
+use crate::*;
+
+#[test]
+fn sizes_round_trip() {
+	for s in ["1..=100", "2..=101:3", "1,2,4,8"] {
+		assert_eq!(s.parse::<Sizes>().unwrap().to_string(), s);
+	}
+	assert_eq!("1..=9:4".parse::<Sizes>().unwrap().to_vec(), [1, 5, 9]);
+	assert!("3..=1".parse::<Sizes>().is_err());
+	assert!("1..=4:0".parse::<Sizes>().is_err());
+	assert!("0,1".parse::<Sizes>().is_err());
+}
+
+#[test]
+fn operations_parse_from_cli_names() {
+	for op in Operation::ALL {
+		assert_eq!(op.name().parse::<Operation>(), Ok(op));
+	}
+	assert_eq!("g1-multiexp".parse::<Operation>(), Ok(Operation::G1MultiExp));
+	assert_eq!("map_g2".parse::<Operation>(), Ok(Operation::MapG2));
+	assert!("g3add".parse::<Operation>().is_err());
+}
+
+/// The last discount applies to every larger pair count.
+#[test]
+fn multiexp_gas_uses_the_discount_table() {
+	let gas = GasSchedule {
+		g1_add: 0,
+		g1_mul: 1000,
+		g2_add: 0,
+		g2_mul: 2000,
+		pairing_base: 100,
+		pairing_per_pair: 10,
+		map_g1: 0,
+		map_g2: 0,
+		g1_multiexp_discount: &[1200, 500],
+		g2_multiexp_discount: &[1000],
+	};
+	assert_eq!(gas.g1_multiexp(0), 0);
+	assert_eq!(gas.g1_multiexp(1), 1200);
+	assert_eq!(gas.g1_multiexp(2), 1000);
+	assert_eq!(gas.g1_multiexp(4), 2000);
+	assert_eq!(gas.g2_multiexp(3), 6000);
+	assert_eq!(gas.pairing(3), 130);
+}
+
+#[test]
+fn duplicate_call_names_are_rejected() {
+	let call = |name: &str| PrecompileCall {
+		name: name.to_string(),
+		input: String::new(),
+		gas: 1,
+		output: None,
+	};
+	assert_eq!(check_unique_names(&[call("a"), call("b")]), Ok(()));
+	assert!(check_unique_names(&[call("a"), call("b"), call("a")]).is_err());
+}
+
+#[test]
+fn foundry_contract_names() {
+	assert_eq!(foundry_contract_name("bn256Add"), "Bn256AddTest");
+	assert_eq!(foundry_contract_name("fail-bls12377G2MultiExp"), "FailBls12377G2MultiExpTest");
+}
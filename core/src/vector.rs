@@ -0,0 +1,112 @@
+use crate::{config::Config, operation::Operation, suite::Suite};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+	collections::BTreeMap,
+	fs::{self, File},
+	io::{self, prelude::*},
+	path::{Path, PathBuf},
+};
+
+/// A `precompiledTest` of go-ethereum's precompile test runner.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VectorSuccess {
+	pub input: String,
+	pub expected: String,
+	/// Gas the precompile charges for `input`, set by [`Operation::gen_vectors`].
+	pub gas: u64,
+	pub name: String,
+	/// Skips the vector in the geth benchmarks, which only time the random, non-trivial inputs.
+	pub no_benchmark: bool,
+}
+
+/// A `precompiledFailureTest` of go-ethereum's precompile test runner.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VectorFail {
+	pub input: String,
+	pub expected_error: String,
+	pub name: String,
+}
+
+/// Parameters a set of vector files was generated with.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Metadata {
+	pub seed: u64,
+	/// Configuration of each generated operation, keyed by [`Operation::name`].
+	#[serde(default)]
+	pub configs: BTreeMap<String, Config>,
+}
+
+impl Metadata {
+	pub fn new(seed: u64) -> Self {
+		Metadata { seed, configs: BTreeMap::new() }
+	}
+
+	/// Configuration `op` was generated with, [`Config::default_for`] if it is not recorded.
+	pub fn config(&self, op: Operation) -> Config {
+		self.configs.get(op.name()).cloned().unwrap_or_else(|| Config::default_for(op))
+	}
+}
+
+/// File name of the success vectors of `op`, e.g. `bls12377G1Add.json`.
+pub fn file_name<S: Suite>(op: Operation) -> String {
+	format!("{}{}.json", S::PREFIX, S::file_op_name(op))
+}
+
+/// File name of the failure vectors of `op`, e.g. `fail-bls12377G1Add.json`.
+pub fn fail_file_name<S: Suite>(op: Operation) -> String {
+	format!("fail-{}{}.json", S::PREFIX, S::file_op_name(op))
+}
+
+/// File name of the generation metadata, e.g. `metadata-bls12377.json`.
+pub fn metadata_file_name<S: Suite>() -> String {
+	format!("metadata-{}.json", S::PREFIX)
+}
+
+/// Pretty prints vectors like the files of go-ethereum's `core/vm/testdata/precompiles`.
+pub fn serialize_vectors<T: Serialize>(vectors: &[T]) -> String {
+	let mut json = serde_json::to_string_pretty(vectors).expect("vectors must serialize");
+	json.push('\n');
+	json
+}
+
+/// Writes the success vectors of `op` into `dir` as a geth precompile test file, returning its
+/// path.
+pub fn write_fixture<S: Suite>(
+	dir: &Path,
+	op: Operation,
+	vectors: &[VectorSuccess],
+) -> io::Result<PathBuf> {
+	let path = dir.join(file_name::<S>(op));
+	fs::write(&path, serialize_vectors(vectors))?;
+	Ok(path)
+}
+
+/// Writes the failure vectors of `op` into `dir` as a geth precompile failure test file,
+/// returning its path.
+pub fn write_fail_fixture<S: Suite>(
+	dir: &Path,
+	op: Operation,
+	vectors: &[VectorFail],
+) -> io::Result<PathBuf> {
+	let path = dir.join(fail_file_name::<S>(op));
+	fs::write(&path, serialize_vectors(vectors))?;
+	Ok(path)
+}
+
+pub fn read_vectors<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+	let file = File::open(path)?;
+	serde_json::from_reader(file).map_err(io::Error::from)
+}
+
+pub fn write_metadata(metadata: &Metadata, path: &Path) -> io::Result<()> {
+	let mut file = File::create(path)?;
+	file.write_all(serde_json::to_string(metadata).expect("metadata must serialize").as_bytes())
+}
+
+pub fn read_metadata(path: &Path) -> io::Result<Metadata> {
+	let file = File::open(path)?;
+	serde_json::from_reader(file).map_err(io::Error::from)
+}
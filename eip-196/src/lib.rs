@@ -0,0 +1,70 @@
+use ark_bn254::{g1, g2, G2Projective};
+use ark_ec::{AffineRepr, CurveGroup};
+use eip_test_gen_core::{decode_point, encode_fe, GasSchedule, InputError, Operation, Suite};
+
+/// BN254 precompiles of EIP-196 (add, mul) and EIP-197 (pairing).
+pub struct Eip196;
+
+impl Suite for Eip196 {
+	type Engine = ark_bn254::Bn254;
+	type G1Config = g1::Config;
+	type G2Config = g2::Config;
+
+	const ADDRESSES: &'static [(Operation, u64)] =
+		&[(Operation::G1Add, 0x06), (Operation::G1Mul, 0x07), (Operation::Pairing, 0x08)];
+	const FE_SIZE: usize = 32;
+	/// Istanbul prices of EIP-1108.
+	const GAS: GasSchedule = GasSchedule {
+		g1_add: 150,
+		g1_mul: 6000,
+		g2_add: 0,
+		g2_mul: 0,
+		pairing_base: 45000,
+		pairing_per_pair: 34000,
+		map_g1: 0,
+		map_g2: 0,
+		g1_multiexp_discount: &[],
+		g2_multiexp_discount: &[],
+	};
+	const OPERATIONS: &'static [Operation] =
+		&[Operation::G1Add, Operation::G1Mul, Operation::Pairing];
+	const PADS_INPUT: bool = true;
+	const PREFIX: &'static str = "bn256";
+	const SCALAR_SIZE: usize = 32;
+	/// Every point of the curve is in G1, EIP-197 requires G2 points to be in the subgroup.
+	const SUBGROUP_CHECKS: &'static [Operation] = &[Operation::Pairing];
+	const WORD_SIZE: usize = 32;
+
+	/// Swaps the halves of each Fq2 element back to `c0 || c1`, see [`Eip196::encode_g2`].
+	fn decode_g2(bytes: &[u8]) -> Result<G2Projective, InputError> {
+		let mut bytes = bytes.to_vec();
+		for fe in bytes.chunks_exact_mut(2 * Self::WORD_SIZE) {
+			let (c1, c0) = fe.split_at_mut(Self::WORD_SIZE);
+			c1.swap_with_slice(c0);
+		}
+		decode_point(&bytes, Self::WORD_SIZE, Self::FE_SIZE)
+	}
+
+	/// The `bn256Add`, `bn256ScalarMul` and `bn256Pairing` test files of go-ethereum.
+	fn file_op_name(op: Operation) -> &'static str {
+		match op {
+			Operation::G1Add => "Add",
+			Operation::G1Mul => "ScalarMul",
+			_ => op.name(),
+		}
+	}
+
+	/// EIP-197 writes an Fq2 element `a * i + b` as `a || b`, i.e. `c1 || c0`.
+	fn encode_g2(g2: G2Projective) -> Vec<u8> {
+		match g2.into_affine().xy() {
+			Some((x, y)) => [x.c1, x.c0, y.c1, y.c0]
+				.into_iter()
+				.flat_map(|fe| encode_fe(fe, Self::WORD_SIZE))
+				.collect(),
+			None => vec![0u8; Self::g2_size()],
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,68 @@
+use crate::Eip196;
+use ark_bn254::G2Projective as G2;
+use ark_ec::Group;
+use eip_test_gen_core::*;
+
+#[test]
+fn encoding_sizes() {
+	assert_eq!(Eip196::g1_size(), 64);
+	assert_eq!(Eip196::g2_size(), 128);
+}
+
+/// Generator of G2 as written in the EIP-197 pairing example.
+#[test]
+fn g2_imaginary_part_first() {
+	assert_eq!(
+		hex::encode(Eip196::encode_g2(G2::generator())),
+		"198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c21800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+	);
+}
+
+#[test]
+fn g2_decodes_imaginary_part_first() {
+	let g2 = Eip196::encode_g2(G2::generator());
+	assert_eq!(Eip196::decode_g2(&g2), Ok(G2::generator()));
+}
+
+/// Add and mul pad an empty input to infinity operands, EIP-197 pairs it to `1`.
+#[test]
+fn empty_input_is_valid() {
+	let infinity = vec![0u8; Eip196::g1_size()];
+	assert_eq!(Operation::G1Add.execute::<Eip196>(&[]), Ok(infinity.clone()));
+	assert_eq!(Operation::G1Mul.execute::<Eip196>(&[]), Ok(infinity));
+	let mut one = vec![0u8; 32];
+	one[31] = 1;
+	assert_eq!(Operation::Pairing.execute::<Eip196>(&[]), Ok(one));
+}
+
+#[test]
+fn reference_precompiles_agree_with_vectors() {
+	check_reference::<Eip196>();
+}
+
+/// The test files go-ethereum keeps for the BN254 precompiles.
+#[test]
+fn geth_file_names() {
+	assert_eq!(file_name::<Eip196>(Operation::G1Add), "bn256Add.json");
+	assert_eq!(file_name::<Eip196>(Operation::G1Mul), "bn256ScalarMul.json");
+	assert_eq!(fail_file_name::<Eip196>(Operation::Pairing), "fail-bn256Pairing.json");
+}
+
+/// The add vectors call `0x06` and expect a success with 64 output bytes in the caller storage.
+#[test]
+fn state_test_filler_of_add() {
+	assert_eq!(Eip196::address(Operation::Pairing), Some(0x08));
+	let config = Config { count: 2, sizes: Sizes::List(vec![]) };
+	let vectors = Operation::G1Add.gen_vectors::<Eip196>(DEFAULT_SEED, &config);
+	let calls: Vec<_> = vectors.iter().map(PrecompileCall::success).collect();
+	let address = Eip196::address(Operation::G1Add).unwrap();
+	let filler = state_test_filler(address, "bn256Add", ">=Cancun", &calls);
+	let test = &filler["bn256Add"];
+	let caller = test["transaction"]["to"].as_str().unwrap();
+	assert!(test["pre"][caller]["code"].as_str().unwrap().contains("call(gas_limit, 0x06"));
+	assert_eq!(test["transaction"]["data"].as_array().unwrap().len(), vectors.len());
+	let storage = &test["expect"][0]["result"][caller]["storage"];
+	assert_eq!(storage["0x00"], "0x00");
+	assert_eq!(storage["0x01"], "0x01");
+	assert_eq!(storage["0x02"], "0x40");
+}
@@ -0,0 +1,79 @@
+use ark_bls12_381::{g1, g2, Fq, Fq2, G1Projective, G2Projective};
+use eip_test_gen_core::{
+	map_to_curve, GasSchedule, InputError, Operation, Suite, BLS12_381_G1_MULTIEXP_DISCOUNT,
+	BLS12_381_G2_MULTIEXP_DISCOUNT,
+};
+
+/// BLS12-381 precompiles of EIP-2537.
+pub struct Eip2537;
+
+impl Suite for Eip2537 {
+	type Engine = ark_bls12_381::Bls12_381;
+	type G1Config = g1::Config;
+	type G2Config = g2::Config;
+
+	/// Addresses of the final EIP-2537 as activated in Prague, which replaced the mul precompiles
+	/// by single pair multiexps.
+	const ADDRESSES: &'static [(Operation, u64)] = &[
+		(Operation::G1Add, 0x0b),
+		(Operation::G1MultiExp, 0x0c),
+		(Operation::G2Add, 0x0d),
+		(Operation::G2MultiExp, 0x0e),
+		(Operation::Pairing, 0x0f),
+		(Operation::MapG1, 0x10),
+		(Operation::MapG2, 0x11),
+	];
+	const FE_SIZE: usize = 48;
+	/// Prices of the final EIP-2537, the muls being the multiexp prices per pair.
+	const GAS: GasSchedule = GasSchedule {
+		g1_add: 375,
+		g1_mul: 12000,
+		g2_add: 600,
+		g2_mul: 22500,
+		pairing_base: 37700,
+		pairing_per_pair: 32600,
+		map_g1: 5500,
+		map_g2: 23800,
+		g1_multiexp_discount: &BLS12_381_G1_MULTIEXP_DISCOUNT,
+		g2_multiexp_discount: &BLS12_381_G2_MULTIEXP_DISCOUNT,
+	};
+	/// The final EIP dropped the mul precompiles.
+	const OPERATIONS: &'static [Operation] = &[
+		Operation::G1Add,
+		Operation::G1MultiExp,
+		Operation::G2Add,
+		Operation::G2MultiExp,
+		Operation::Pairing,
+		Operation::MapG1,
+		Operation::MapG2,
+	];
+	const PREFIX: &'static str = "bls";
+	const SCALAR_SIZE: usize = 32;
+	/// Add and the maps skip the subgroup check, every other operation performs it.
+	const SUBGROUP_CHECKS: &'static [Operation] =
+		&[Operation::G1MultiExp, Operation::G2MultiExp, Operation::Pairing];
+	const WORD_SIZE: usize = 64;
+
+	/// The errors of go-ethereum, the modulus check being the one of gnark-crypto.
+	fn error_message(error: InputError) -> &'static str {
+		match error {
+			InputError::InvalidLength => "invalid input length",
+			InputError::InvalidFieldElementTopBytes => "invalid field element top bytes",
+			InputError::InvalidFieldElement => "invalid fp.Element encoding",
+			InputError::PointNotOnCurve => "invalid point: not on curve",
+			InputError::G1PointNotInSubgroup => "g1 point is not on correct subgroup",
+			InputError::G2PointNotInSubgroup => "g2 point is not on correct subgroup",
+		}
+	}
+
+	fn map_to_g1(fe: Fq) -> Option<G1Projective> {
+		Some(map_to_curve::<g1::Config>(fe))
+	}
+
+	fn map_to_g2(fe: Fq2) -> Option<G2Projective> {
+		Some(map_to_curve::<g2::Config>(fe))
+	}
+}
+
+#[cfg(test)]
+mod tests;
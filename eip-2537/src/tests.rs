@@ -0,0 +1,124 @@
+use crate::Eip2537;
+use ark_bls12_381::{Fq, Fq2, G1Projective as G1};
+use ark_ec::Group;
+use eip_test_gen_core::*;
+
+#[test]
+fn encoding_sizes() {
+	assert_eq!(Eip2537::g1_size(), 128);
+	assert_eq!(Eip2537::g2_size(), 256);
+}
+
+#[test]
+fn empty_input_is_invalid() {
+	for &op in Eip2537::OPERATIONS {
+		assert_eq!(op.execute::<Eip2537>(&[]), Err(InputError::InvalidLength), "{op}");
+		assert!(!op.input_length::<Eip2537>().accepts(0), "{op}");
+	}
+}
+
+#[test]
+fn decoders_are_strict() {
+	let g = Eip2537::encode_g1(G1::generator());
+	assert_eq!(Eip2537::decode_g1(&g), Ok(G1::generator()));
+	assert_eq!(Eip2537::decode_g1(&[0u8; 128]), Ok(G1::default()));
+	assert_eq!(Eip2537::decode_g1(&g[1..]), Err(InputError::InvalidLength));
+
+	let mut padding = g.clone();
+	padding[0] = 1;
+	assert_eq!(Eip2537::decode_g1(&padding), Err(InputError::InvalidFieldElementTopBytes));
+	let mut large = g.clone();
+	large[16..64].fill(0xff);
+	assert_eq!(Eip2537::decode_g1(&large), Err(InputError::InvalidFieldElement));
+	let mut off_curve = g;
+	off_curve[127] ^= 1;
+	assert_eq!(Eip2537::decode_g1(&off_curve), Err(InputError::PointNotOnCurve));
+
+	let outside = Eip2537::rand_g2_point_not_on_correct_subgroup(&mut seeded_rng(DEFAULT_SEED));
+	let outside = Eip2537::encode_g2(outside);
+	assert!(Eip2537::decode_g2(&outside).is_ok());
+	assert_eq!(Eip2537::decode_g2_in_subgroup(&outside), Err(InputError::G2PointNotInSubgroup));
+}
+
+/// First vectors of the geth `map_fp_to_G1_bls.json` and `map_fp2_to_G2_bls.json`.
+#[test]
+fn maps_match_geth() {
+	let fe: Fq = "3297453399026529404493391225421873154938040315357981303415954598599539802924806733815287302637231826557354531118851".parse().unwrap();
+	assert_eq!(
+		hex::encode(Eip2537::encode_g1(Eip2537::map_to_g1(fe).unwrap())),
+		"00000000000000000000000000000000184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba0000000000000000000000000000000004407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3"
+	);
+	let c0: Fq = "1109482393867147957994512016591654623852510627789022390193503417017414390774377881245672617676242383930689007991556".parse().unwrap();
+	let c1: Fq = "386356132588844619497041857532935296912445628687296060456129116102048759232807033490958705489483482319494998177676".parse().unwrap();
+	assert_eq!(
+		hex::encode(Eip2537::encode_g2(Eip2537::map_to_g2(Fq2::new(c0, c1)).unwrap())),
+		"0000000000000000000000000000000000e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb700000000000000000000000000000000126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b000000000000000000000000000000000caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42000000000000000000000000000000001498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d"
+	);
+}
+
+/// Input lengths and gas of the geth fixtures of Prague.
+#[test]
+fn gas_matches_geth() {
+	for (op, input_len, gas) in [
+		(Operation::G1Add, 256, 375),
+		(Operation::G2Add, 512, 600),
+		(Operation::G1MultiExp, 160, 12000),
+		(Operation::G1MultiExp, 1120, 61992),
+		(Operation::G2MultiExp, 288, 22500),
+		(Operation::G2MultiExp, 576, 45000),
+		(Operation::G2MultiExp, 1728, 112320),
+		(Operation::Pairing, 1152, 135500),
+		(Operation::MapG1, 64, 5500),
+		(Operation::MapG2, 128, 23800),
+	] {
+		assert_eq!(op.gas::<Eip2537>(input_len), gas, "{op}");
+	}
+}
+
+#[test]
+fn reference_precompiles_agree_with_vectors() {
+	check_reference::<Eip2537>();
+}
+
+/// Prague put G1 add at `0x0b`, after the point evaluation precompile, and has no mul.
+#[test]
+fn prague_addresses() {
+	assert_eq!(Eip2537::address(Operation::G1Add), Some(0x0b));
+	assert_eq!(Eip2537::address(Operation::G1Mul), None);
+	assert_eq!(Eip2537::address(Operation::MapG2), Some(0x11));
+	assert!(!Eip2537::OPERATIONS.contains(&Operation::G2Mul));
+}
+
+/// Failure vectors carry the errors of the geth `fail-bls*.json` fixtures.
+#[test]
+fn fail_vectors_use_geth_errors() {
+	let errors: Vec<_> = Operation::G1Add
+		.gen_fail_vectors::<Eip2537>(DEFAULT_SEED)
+		.into_iter()
+		.map(|vector| vector.expected_error)
+		.collect();
+	for error in [
+		"invalid input length",
+		"invalid field element top bytes",
+		"invalid fp.Element encoding",
+		"invalid point: not on curve",
+	] {
+		assert!(errors.iter().any(|e| e == error), "{error}");
+	}
+	assert!(!errors.iter().any(|e| e == "invalid Fq"));
+}
+
+/// Prague fillers forward the Prague prices, e.g. 375 gas to G1 add and 12000 to a single pair
+/// G1 multiexp.
+#[test]
+fn state_test_fillers_use_prague_gas() {
+	let config = Config { count: 1, sizes: Sizes::List(vec![1]) };
+	for (op, gas) in [(Operation::G1Add, 375), (Operation::G1MultiExp, 12000)] {
+		let vectors = op.gen_vectors::<Eip2537>(DEFAULT_SEED, &config);
+		let calls: Vec<_> = vectors.iter().map(PrecompileCall::success).collect();
+		let address = Eip2537::address(op).unwrap();
+		let filler = state_test_filler(address, "prague", ">=Prague", &calls);
+		let data = filler["prague"]["transaction"]["data"][0].as_str().unwrap();
+		assert!(data.contains(&format!(":raw 0x{gas:064x}")), "{op} {data}");
+	}
+}
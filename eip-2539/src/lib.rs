@@ -1,2 +1,60 @@
+use ark_bls12_377::{g1, g2, Fq, Fq2, G1Projective, G2Projective};
+use eip_test_gen_core::{map_to_curve, GasSchedule, Operation, Suite, BLS12_MULTIEXP_DISCOUNT};
+
+/// BLS12-377 precompiles of EIP-2539.
+pub struct Eip2539;
+
+impl Suite for Eip2539 {
+	type Engine = ark_bls12_377::Bls12_377;
+	type G1Config = g1::Config;
+	type G2Config = g2::Config;
+
+	/// EIP-2539 leaves the addresses open, take the ones following the EIP-2537 precompiles.
+	const ADDRESSES: &'static [(Operation, u64)] = &[
+		(Operation::G1Add, 0x12),
+		(Operation::G1Mul, 0x13),
+		(Operation::G1MultiExp, 0x14),
+		(Operation::G2Add, 0x15),
+		(Operation::G2Mul, 0x16),
+		(Operation::G2MultiExp, 0x17),
+		(Operation::Pairing, 0x18),
+		(Operation::MapG1, 0x19),
+		(Operation::MapG2, 0x1a),
+	];
+	const FE_SIZE: usize = 48;
+	const GAS: GasSchedule = GasSchedule {
+		g1_add: 600,
+		g1_mul: 12000,
+		g2_add: 4500,
+		g2_mul: 55000,
+		pairing_base: 65000,
+		pairing_per_pair: 55000,
+		map_g1: 5500,
+		map_g2: 75000,
+		g1_multiexp_discount: &BLS12_MULTIEXP_DISCOUNT,
+		g2_multiexp_discount: &BLS12_MULTIEXP_DISCOUNT,
+	};
+	const OPERATIONS: &'static [Operation] = &Operation::ALL;
+	const PREFIX: &'static str = "bls12377";
+	const SCALAR_SIZE: usize = 32;
+	/// Like EIP-2537, add and the maps skip the subgroup check.
+	const SUBGROUP_CHECKS: &'static [Operation] = &[
+		Operation::G1Mul,
+		Operation::G1MultiExp,
+		Operation::G2Mul,
+		Operation::G2MultiExp,
+		Operation::Pairing,
+	];
+	const WORD_SIZE: usize = 64;
+
+	fn map_to_g1(fe: Fq) -> Option<G1Projective> {
+		Some(map_to_curve::<g1::Config>(fe))
+	}
+
+	fn map_to_g2(fe: Fq2) -> Option<G2Projective> {
+		Some(map_to_curve::<g2::Config>(fe))
+	}
+}
+
 #[cfg(test)]
 mod tests;
@@ -11,7 +11,7 @@ fn bench_bn_pairing(c: &mut Criterion) {
 
 	let mut group = c.benchmark_group("Bn");
 	group.sample_size(1000);
-	group.bench_function(&format!("Pairing for {SAMPLES} samples"), |b| {
+	group.bench_function(format!("Pairing for {SAMPLES} samples"), |b| {
 		let mut i = 0;
 		b.iter(|| {
 			i = (i + 1) % SAMPLES;
@@ -142,7 +142,7 @@ mod pairing {
 		let pairs: [usize; 5] = [2, 4, 8, 12, 16];
 		let mut rng = ark_std::test_rng();
 
-		let mut group = c.benchmark_group(format!("Pairing for {}", stringify!(BW6_671)));
+		let mut group = c.benchmark_group(format!("Pairing for {}", stringify!(BW6_761)));
 		for num_pair in pairs.iter() {
 			let sample = *num_pair;
 			let g1s = (0..sample).map(|_| G1::rand(&mut rng)).collect::<Vec<_>>();
@@ -12,7 +12,7 @@ fn bench_ecrevocer(c: &mut Criterion) {
 
 	let mut group = c.benchmark_group("ECRECOVER");
 	group.sample_size(1000);
-	group.bench_function(&format!("ECRECOVER for {SAMPLES} samples"), |b| {
+	group.bench_function(format!("ECRECOVER for {SAMPLES} samples"), |b| {
 		let mut i = 0;
 		b.iter(|| {
 			i = (i + 1) % SAMPLES;
@@ -1,2 +1,60 @@
+use eip_test_gen_core::{GasSchedule, Operation, Suite};
+
+/// Multiexp discounts of EIP-3026, measured in `gas-meter/benches.md`.
+const MULTIEXP_DISCOUNT: [u64; 128] = [
+	1266, 733, 561, 474, 422, 387, 362, 344, 329, 318, 308, 300, 296, 289, 283, 279, 275, 272, 269,
+	266, 265, 260, 259, 256, 255, 254, 252, 251, 250, 249, 249, 220, 228, 225, 223, 219, 216, 214,
+	212, 209, 209, 205, 203, 202, 200, 198, 196, 199, 195, 192, 192, 191, 190, 187, 186, 185, 184,
+	184, 181, 181, 181, 180, 178, 179, 176, 177, 176, 175, 174, 173, 171, 171, 170, 170, 169, 168,
+	168, 167, 167, 166, 165, 167, 166, 166, 165, 165, 164, 164, 163, 163, 162, 162, 160, 163, 159,
+	162, 159, 160, 159, 159, 158, 158, 158, 158, 157, 157, 156, 155, 155, 156, 155, 155, 154, 155,
+	154, 153, 153, 153, 152, 152, 152, 152, 151, 151, 150, 151, 151, 151,
+];
+
+/// BW6-761 precompiles of EIP-3026.
+pub struct Eip3026;
+
+impl Suite for Eip3026 {
+	type Engine = ark_bw6_761::BW6_761;
+	type G1Config = ark_bw6_761::g1::Config;
+	type G2Config = ark_bw6_761::g2::Config;
+
+	/// EIP-3026 leaves the addresses open, take the ones following the EIP-2539 precompiles.
+	const ADDRESSES: &'static [(Operation, u64)] = &[
+		(Operation::G1Add, 0x1b),
+		(Operation::G1Mul, 0x1c),
+		(Operation::G1MultiExp, 0x1d),
+		(Operation::G2Add, 0x1e),
+		(Operation::G2Mul, 0x1f),
+		(Operation::G2MultiExp, 0x20),
+		(Operation::Pairing, 0x21),
+	];
+	const FE_SIZE: usize = 96;
+	const GAS: GasSchedule = GasSchedule {
+		g1_add: 180,
+		g1_mul: 64000,
+		g2_add: 180,
+		g2_mul: 64000,
+		pairing_base: 320000,
+		pairing_per_pair: 120000,
+		map_g1: 0,
+		map_g2: 0,
+		g1_multiexp_discount: &MULTIEXP_DISCOUNT,
+		g2_multiexp_discount: &MULTIEXP_DISCOUNT,
+	};
+	const PREFIX: &'static str = "bw6761";
+	const SCALAR_SIZE: usize = 64;
+	/// Whether mul and multiexp check the subgroup depends on the EIP-3026 revision, these
+	/// vectors follow the one checking it in every operation but add.
+	const SUBGROUP_CHECKS: &'static [Operation] = &[
+		Operation::G1Mul,
+		Operation::G1MultiExp,
+		Operation::G2Mul,
+		Operation::G2MultiExp,
+		Operation::Pairing,
+	];
+	const WORD_SIZE: usize = 96;
+}
+
 #[cfg(test)]
 mod tests;